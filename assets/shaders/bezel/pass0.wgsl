@@ -0,0 +1,43 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::bezel::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+@group(0) @binding(6) var bezel_texture: texture_2d<f32>;
+@group(0) @binding(7) var bezel_sampler: sampler;
+
+fn inside(uv: vec2f) -> bool {
+	return all(uv >= vec2f(0.0)) && all(uv <= vec2f(1.0));
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	// viewport uv to bezel image uv
+	let bezel_uv = (in.uv - 0.5) * settings.uv_scale + 0.5;
+
+	// the whole picture, curvature included, is squeezed into the screen rect
+	let screen_min = settings.screen_rect.xy;
+	let screen_size = settings.screen_rect.zw - settings.screen_rect.xy;
+	let screen_uv = (bezel_uv - screen_min) / screen_size;
+
+	let picture = sample_viewport(screen_texture, texture_sampler, screen_uv, view.viewport);
+	let bezel = textureSample(bezel_texture, bezel_sampler, bezel_uv);
+
+	let picture_color = select(vec3f(0.0), picture.rgb, inside(screen_uv));
+	let bezel_color = vec4f(bezel.rgb, select(0.0, bezel.a, inside(bezel_uv)));
+
+	// black bars where the bezel doesn't reach
+	let color = select(vec3f(0.0), mix(picture_color, bezel_color.rgb, bezel_color.a), inside(bezel_uv));
+
+	return vec4f(color, picture.a);
+}
@@ -0,0 +1,40 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::convergence::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+
+// offset of a channel in texels at the given point, -1 to 1 from the center to the edges
+fn channel_offset(static_offset: vec2f, dynamic_offset: vec2f, centered: vec2f) -> vec2f {
+	// deflection errors grow roughly with the square of the distance from the center,
+	// half of it because the squared distance is 2 in the corners
+	let falloff = dot(centered, centered) * 0.5;
+
+	return static_offset + dynamic_offset * centered * falloff;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	let texel = 1.0 / view.viewport.zw;
+	let centered = in.uv * 2.0 - 1.0;
+
+	let red_uv = in.uv - channel_offset(settings.red_static, settings.red_dynamic, centered) * texel;
+	let green_uv = in.uv - channel_offset(settings.green_static, settings.green_dynamic, centered) * texel;
+	let blue_uv = in.uv - channel_offset(settings.blue_static, settings.blue_dynamic, centered) * texel;
+
+	let red = sample_viewport(screen_texture, texture_sampler, red_uv, view.viewport);
+	let green = sample_viewport(screen_texture, texture_sampler, green_uv, view.viewport);
+	let blue = sample_viewport(screen_texture, texture_sampler, blue_uv, view.viewport);
+
+	return vec4f(red.r, green.g, blue.b, green.a);
+}
@@ -0,0 +1,58 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::degauss::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+
+const TAU = 6.28318531;
+
+fn rotate(v: vec2f, angle: f32) -> vec2f {
+	let s = sin(angle);
+	let c = cos(angle);
+	return vec2f(v.x * c - v.y * s, v.x * s + v.y * c);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	var elapsed = globals.time - settings.started_at;
+	if elapsed < 0.0 {
+		elapsed += settings.wrap_period;
+	}
+
+	let progress = saturate(elapsed / max(settings.duration, 0.001));
+
+	// sharp hit, then an oscillating decay that settles exactly at the end
+	let decay = exp(-4.0 * progress) * (1.0 - progress);
+
+	let aspect = view.viewport.z / view.viewport.w;
+	let centered = (in.uv - 0.5) * vec2f(aspect, 1.0);
+	let radius = length(centered);
+
+	// the field swirls the picture around the center, more so in the middle
+	let swirl = settings.strength * decay * 0.6 * sin(elapsed * 25.0) * exp(-radius * radius * 3.0);
+
+	// and wobbles it in waves
+	let wobble = sin(centered.yx * 12.0 + elapsed * vec2f(31.0, 27.0)) * 0.01 * settings.strength * decay;
+
+	let uv = rotate(centered, swirl) / vec2f(aspect, 1.0) + 0.5 + wobble;
+
+	let sample = sample_viewport(screen_texture, texture_sampler, uv, view.viewport);
+
+	// beams land on the wrong phosphors, colored rings sweep across the screen
+	let rings = 0.5 + 0.5 * cos(TAU * (vec3f(0.0, 0.33, 0.67) + radius * 3.0 - elapsed * 2.0));
+	let tinted = sample.rgb * rings * 2.0;
+
+	let color = mix(sample.rgb, tinted, saturate(settings.purity * decay));
+
+	return vec4f(color, sample.a);
+}
@@ -3,6 +3,8 @@
 
 #import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
 #import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
 #import bevy_crt_galore::endesga::CrtSettings
 
 alias vec2f = vec2<f32>;
@@ -13,6 +15,7 @@ alias vec4f = vec4<f32>;
 @group(0) @binding(1) var texture_sampler: sampler;
 @group(0) @binding(2) var<uniform> settings: CrtSettings;
 @group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
 
 fn hash(p: vec3f) -> f32 {
 	var p_var = p;
@@ -36,17 +39,33 @@ fn grain(x: vec3f) -> f32 {
 
 @fragment
 fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
+	let resolution = view.viewport.zw;
+
+	let frag_coord: vec2f = in.uv * resolution.xy;
+
+#ifdef ABERRATION
 	let aber_dis: vec2f = (in.uv - vec2f(0.5)) * settings.aberration_amount * length(in.uv - 0.5);
-	let aberration: vec3f = vec3f(
-		textureSample(screen_texture, texture_sampler, in.uv).r,
-		textureSample(screen_texture, texture_sampler, in.uv - aber_dis).g,
-		textureSample(screen_texture, texture_sampler, in.uv - 2. * aber_dis).b
+	var color: vec3f = vec3f(
+		sample_viewport(screen_texture, texture_sampler, in.uv, view.viewport).r,
+		sample_viewport(screen_texture, texture_sampler, in.uv - aber_dis, view.viewport).g,
+		sample_viewport(screen_texture, texture_sampler, in.uv - 2. * aber_dis, view.viewport).b
 	);
+#else
+	var color: vec3f = sample_viewport(screen_texture, texture_sampler, in.uv, view.viewport).rgb;
+#endif
 
-	let resolution = vec2f(textureDimensions(screen_texture));
+#ifdef NOISE
+	let frame: f32 = floor(f32(globals.frame_count));
+	let rgb_grain = vec3f(
+		grain(vec3f(frag_coord, frame)),
+		grain(vec3f(frag_coord, frame + 9.0)),
+		grain(vec3f(frag_coord, frame - 9.0))
+	);
 
-	let frag_coord: vec2f = in.uv * resolution.xy;
+	color = mix(color, mix(color * rgb_grain, color + (rgb_grain - 1.0), 0.5), settings.noise_amount);
+#endif
 
+#ifdef VIGNETTE
 	let screen_ratio_y = resolution.y / resolution.x;
 
 	let vignette_step = smoothstep(
@@ -61,6 +80,10 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
 		settings.vignette_amount
 	);
 
+	color *= vignette;
+#endif
+
+#ifdef ROUNDED
 	let half_res = resolution / 2.0;
 	let radius = settings.rounded_amount * ((resolution.x + resolution.y) * 0.5) * 0.5;
 
@@ -74,14 +97,8 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
 		0.0
 	);
 
-	let frame: f32 = floor(f32(globals.frame_count));
-	let rgb_grain = vec3f(
-		grain(vec3f(frag_coord, frame)),
-		grain(vec3f(frag_coord, frame + 9.0)),
-		grain(vec3f(frag_coord, frame - 9.0))
-	);
-
-	let aberration_wgrain = mix(aberration, mix(aberration * rgb_grain, aberration + (rgb_grain - 1.0), 0.5), settings.noise_amount);
+	color *= rounded_corners;
+#endif
 
-	return vec4f(aberration_wgrain * vignette * rounded_corners, 1.0);
+	return vec4f(color, 1.0);
 }
@@ -3,22 +3,23 @@
 
 #import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
 #import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
 #import bevy_crt_galore::endesga::CrtSettings
+#import bevy_crt_galore::mask::crt_mask
 
 alias vec2f = vec2<f32>;
 alias vec3f = vec3<f32>;
 alias vec4f = vec4<f32>;
 
-const X = vec3f( 0.0 );
-const R = vec3f( 1.0, 0.0, 0.0 );
-const G = vec3f( 0.0, 1.0, 0.0 );
-const B = vec3f( 0.0, 0.0, 1.0 );
-// var M = array<vec3f, 28>( X, X, X, X, X, X, X, X, R, R, G, G, B, B, X, R, R, G, G, B, B, X, R, R, G, G, B, B );
-
 @group(0) @binding(0) var screen_texture: texture_2d<f32>;
 @group(0) @binding(1) var texture_sampler: sampler;
 @group(0) @binding(2) var<uniform> settings: CrtSettings;
 @group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+// white fallback without CrtMaskTexture
+@group(0) @binding(6) var mask_texture: texture_2d<f32>;
+@group(0) @binding(7) var mask_sampler: sampler;
 
 fn modulo(a: f32, b: f32) -> f32 {
 	var m = a % b;
@@ -34,7 +35,7 @@ fn modulo(a: f32, b: f32) -> f32 {
 
 @fragment
 fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
-	let resolution = vec2f(textureDimensions(screen_texture));
+	let resolution = view.viewport.zw;
 
 	let frag_coord : vec2f = in.uv * resolution.xy;
 
@@ -44,29 +45,31 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
 
 	uv.y += floor(modulo(frag_coord.y, 4.) / 2.) * hex_offset * .5;
 
+	var output : vec4f = sample_viewport(screen_texture, texture_sampler, frag_coord / resolution.xy, view.viewport);
+
+#ifdef PIXELATE
 	// 7x4 pixelation
-	var output : vec4f = vec4f(0.0);
+	var pixelated : vec4f = vec4f(0.0);
 
 	for(var y = 0.0; y < 4.; y += 1.0) {
 		for(var x = 0.0; x < 7.; x += 1.0) {
-			output += textureSample(screen_texture, texture_sampler, ((uv * vec2f(7., 4.)) + vec2f(x, y)) / resolution.xy);
+			pixelated += sample_viewport(screen_texture, texture_sampler, ((uv * vec2f(7., 4.)) + vec2f(x, y)) / resolution.xy, view.viewport);
 		}
 	}
 
-	output = mix(textureSample(screen_texture, texture_sampler, frag_coord / resolution.xy), output / 28., settings.pixelate_amount);
-
-	// this should be const, waiting for resolution of this: https://github.com/gfx-rs/wgpu/issues/4337
-	var M = array<vec3f, 28>( X, X, X, X, X, X, X, X, R, R, G, G, B, B, X, R, R, G, G, B, B, X, R, R, G, G, B, B );
+	output = mix(output, pixelated / 28., settings.pixelate_amount);
+#endif
 
+#ifdef MASK
 	let output_rgb = output.rgb * mix(
 		vec3(1.),
-		// 7x4 sub-pixel RGB mask
-		M[i32(
-			i32(modulo(frag_coord.y + hex_offset, 4.0)) * 7 +
-			i32(modulo(frag_coord.x, 7.0))
-		)],
+		// sub-pixel RGB mask of the selected type, 7x4 hex by default
+		crt_mask(frag_coord, settings.mask_type, settings.mask_size, settings.mask_border, mask_texture, mask_sampler),
 		settings.mask_amount
 	);
 
-	return vec4f(output_rgb, output.w);
+	output = vec4f(output_rgb, output.w);
+#endif
+
+	return output;
 }
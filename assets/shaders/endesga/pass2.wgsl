@@ -3,6 +3,8 @@
 
 #import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
 #import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
 #import bevy_crt_galore::endesga::CrtSettings
 
 alias vec2f = vec2<f32>;
@@ -13,6 +15,7 @@ alias vec4f = vec4<f32>;
 @group(0) @binding(1) var texture_sampler: sampler;
 @group(0) @binding(2) var<uniform> settings: CrtSettings;
 @group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
 
 fn modulo(a: f32, b: f32) -> f32 {
 	var m = a % b;
@@ -28,24 +31,32 @@ fn modulo(a: f32, b: f32) -> f32 {
 
 @fragment
 fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
-    let resolution = vec2f(textureDimensions(screen_texture));
+	var uv = in.uv;
 
-	let frag_coord : vec2f = in.uv * resolution.xy;
-
-	var uv = (in.uv * 2.) - 1.;
+#ifdef DISTORTION
+	uv = (uv * 2.) - 1.;
 
 	let r = length(uv);
 
 	uv /= (2. * settings.distortion_amount * r * r);
 
 	uv = ((uv * (1. - sqrt(1. - 4. * settings.distortion_amount * r * r))) + 1.) / 2.;
+#endif
+
+	var output = sample_viewport(screen_texture, texture_sampler, uv, view.viewport);
 
+#ifdef GLOW
+	output *= settings.glow_amount;
+#endif
+
+#ifdef DISTORTION
 	let v : f32 = min(min(uv.x, 1. - uv.x), min(uv.y, 1. - uv.y));
 
 	let AA : f32 = 0.5 * length(vec2f(dpdx(v), dpdy(v)));
 
-	return textureSample(screen_texture, texture_sampler, uv) * settings.glow_amount
 	// barrel-distortion mask
-	* smoothstep( -AA, AA, v );
+	output *= smoothstep( -AA, AA, v );
+#endif
 
-}
\ No newline at end of file
+	return output;
+}
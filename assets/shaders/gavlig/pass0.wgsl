@@ -1,5 +1,7 @@
 #import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
 #import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
 #import bevy_crt_galore::gavlig::CrtSettings
 
 alias vec2f = vec2<f32>;
@@ -10,6 +12,7 @@ alias vec4f = vec4<f32>;
 @group(0) @binding(1) var texture_sampler: sampler;
 @group(0) @binding(2) var<uniform> settings: CrtSettings;
 @group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
 
 fn calc_grain(st: vec2<f32>) -> f32 {
     return fract(sin(dot(st.xy, vec2(17.0,180.)))* 2500. + globals.time);
@@ -33,18 +36,25 @@ fn calc_vignette(uv: vec2<f32>) -> f32 {
 
 @fragment
 fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
-	let sample = textureSample(screen_texture, texture_sampler, in.uv);
+	let sample = sample_viewport(screen_texture, texture_sampler, in.uv, view.viewport);
 
-	let color = sample.rgb;
+	var color = sample.rgb;
 
-	let vignette_alpha_inv = 1.0 - settings.vignette_alpha;
+#ifdef GRAIN
+    let grain = vec3<f32>(calc_grain(in.uv));
+
+	color = mix(color, grain, settings.grain_strength * 0.1);
+#endif
 
-	let vignette = saturate(calc_vignette(in.uv) + vignette_alpha_inv);
+#ifdef VIGNETTE
+	let vignette_alpha_inv = 1.0 - settings.vignette_alpha;
 
-	var glow = 1.0;
-	if length(color) > settings.glow_threshold { glow = settings.glow_strength; }
+	color *= saturate(calc_vignette(in.uv) + vignette_alpha_inv);
+#endif
 
-    let grain = vec3<f32>(calc_grain(in.uv));
+#ifdef GLOW
+	if length(sample.rgb) > settings.glow_threshold { color *= settings.glow_strength; }
+#endif
 
-	return vec4<f32>(mix(color, grain, settings.grain_strength * 0.1) * vignette * glow, sample.a);
-}
\ No newline at end of file
+	return vec4<f32>(color, sample.a);
+}
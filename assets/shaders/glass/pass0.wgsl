@@ -0,0 +1,58 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::glass::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+// white fallback without a reflection texture
+@group(0) @binding(6) var reflection_texture: texture_2d<f32>;
+@group(0) @binding(7) var reflection_sampler: sampler;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	let centered = in.uv * 2.0 - 1.0;
+
+	// the glass bulges towards the viewer, its slope grows towards the edges
+	let slope = centered * settings.curvature;
+
+	var uv = in.uv;
+
+#ifdef REFRACTION
+	// light passes the curved glass at an angle near the edges, so the picture seems pushed outward
+	uv -= slope * dot(centered, centered) * settings.refraction;
+#endif
+
+	let sample = sample_viewport(screen_texture, texture_sampler, uv, view.viewport);
+
+	var color = sample.rgb;
+
+	// the reflected room moves along the curved surface
+	let reflection_uv = in.uv + slope * 0.5;
+
+	// glass reflects more at grazing angles
+	let fresnel = 0.5 + 0.5 * saturate(dot(slope, slope) * 4.0);
+
+	let reflection = textureSample(reflection_texture, reflection_sampler, vec2f(1.0 - reflection_uv.x, reflection_uv.y)).rgb;
+
+#ifdef REFLECTION
+	color += reflection * settings.reflection_color * settings.reflection_intensity * fresnel;
+#endif
+
+#ifdef GLARE
+	let aspect = view.viewport.z / view.viewport.w;
+	let glare_offset = (reflection_uv - settings.glare_position) * vec2f(aspect, 1.0);
+	let glare = exp(-dot(glare_offset, glare_offset) / max(settings.glare_size * settings.glare_size, 0.0001));
+	color += vec3f(glare * settings.glare_intensity);
+#endif
+
+	return vec4f(color, sample.a);
+}
@@ -0,0 +1,57 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::halation::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+
+// samples of the spiral, enough to look smooth with linear filtering at the default radii
+const SAMPLES = 48;
+const GOLDEN_ANGLE = 2.39996323;
+const TAU = 6.28318531;
+
+// Jimenez's interleaved gradient noise, neighbouring pixels get well spread values
+fn interleaved_gradient_noise(pixel: vec2f) -> f32 {
+	return fract(52.9829189 * fract(dot(pixel, vec2f(0.06711056, 0.00583715))));
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	let sample = sample_viewport(screen_texture, texture_sampler, in.uv, view.viewport);
+
+	// radius is in screen heights, keep the disc round
+	let radius = vec2f(settings.radius * view.viewport.w / view.viewport.z, settings.radius);
+
+	// the spiral is rotated per pixel so that sparse taps around small bright spots blur into noise instead of copies
+	let rotation = interleaved_gradient_noise(floor(in.uv * view.viewport.zw)) * TAU;
+
+	// single pass golden angle spiral, a separable blur would lose the sharp picture between passes
+	var light = vec3f(0.0);
+	var weights = 0.0;
+
+	for (var i = 0; i < SAMPLES; i++) {
+		let r = sqrt((f32(i) + 0.5) / f32(SAMPLES));
+		let theta = f32(i) * GOLDEN_ANGLE + rotation;
+
+		let offset = vec2f(cos(theta), sin(theta)) * r * radius;
+
+		// gaussian-ish falloff towards the edge of the disc
+		let weight = exp(-3.0 * r * r);
+
+		light += max(sample_viewport(screen_texture, texture_sampler, in.uv + offset, view.viewport).rgb - settings.threshold, vec3f(0.0)) * weight;
+		weights += weight;
+	}
+
+	let color = sample.rgb + light / weights * settings.strength;
+
+	return vec4f(color, sample.a);
+}
@@ -0,0 +1,59 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::instability::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+
+const TAU = 6.28318531;
+
+fn hash(p: vec2f) -> f32 {
+	return fract(sin(dot(p, vec2f(12.9898, 78.233))) * 43758.5453);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	var uv = in.uv;
+
+	// one line height in screen widths
+	let line_width = view.viewport.w / (view.viewport.z * settings.line_count);
+
+	var blanking = false;
+
+#ifdef ROLL
+	// the picture rolls together with its blanking bar as one frame a bit taller than the screen
+	let frame_height = 1.0 + settings.blanking_height;
+	let roll = fract(globals.time * settings.roll_speed / frame_height);
+	let y = fract(uv.y / frame_height - roll) * frame_height;
+
+	blanking = y > 1.0;
+	uv.y = min(y, 1.0);
+#endif
+
+	let line = floor(uv.y * settings.line_count);
+
+#ifdef JITTER
+	let frame = f32(globals.frame_count % 65536u);
+	uv.x += (hash(vec2f(line, frame)) * 2.0 - 1.0) * settings.jitter * line_width;
+#endif
+
+#ifdef WOBBLE
+	let wobble_phase = uv.y * settings.wobble_frequency - globals.time * settings.wobble_speed;
+	uv.x += sin(wobble_phase * TAU) * settings.wobble_amplitude * line_width;
+#endif
+
+	let sample = sample_viewport(screen_texture, texture_sampler, uv, view.viewport);
+
+	let black = blanking || uv.x < 0.0 || uv.x > 1.0;
+
+	return select(sample, vec4f(0.0, 0.0, 0.0, sample.a), black);
+}
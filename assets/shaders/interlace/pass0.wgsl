@@ -0,0 +1,54 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::interlace::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+@group(0) @binding(5) var history_texture: texture_2d<f32>;
+
+struct FragmentOutput {
+	@location(0) color: vec4f,
+	@location(1) history: vec4f,
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> FragmentOutput {
+	let line = floor(in.uv.y * settings.line_count);
+	let line_center = (line + 0.5) / settings.line_count;
+
+	// everything is sampled up front, textureSample has to stay in uniform control flow
+	let sample = sample_viewport(screen_texture, texture_sampler, vec2f(in.uv.x, line_center), view.viewport);
+
+	// the other field, doubled from the neighbouring line of the current one
+	var color = sample_viewport(screen_texture, texture_sampler, vec2f(in.uv.x, line_center - 1.0 / settings.line_count), view.viewport);
+
+#ifdef COMBING
+	// or woven from the previous frame so that anything moving gets combed
+	color = mix(color, sample_viewport(history_texture, texture_sampler, in.uv, view.viewport), settings.combing);
+#endif
+
+	let history = color;
+
+#ifdef FLICKER
+	color = vec4f(color.rgb * (1.0 - settings.flicker), color.a);
+#endif
+
+	// even lines on even frames, odd lines on odd ones
+	let field = globals.frame_count % 2u;
+	let current_field = u32(line) % 2u == field;
+
+	var out: FragmentOutput;
+	out.color = select(color, sample, current_field);
+	out.history = select(history, sample, current_field);
+
+	return out;
+}
@@ -0,0 +1,94 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::ntsc::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+
+const PI = 3.14159265;
+const TAU = 6.28318531;
+const GAMMA = 2.2;
+
+// samples on each side of the decoded one
+const TAPS = 12;
+
+const RGB_TO_YIQ = mat3x3<f32>(
+	vec3f(0.299,  0.596,  0.211),
+	vec3f(0.587, -0.274, -0.523),
+	vec3f(0.114, -0.322,  0.312),
+);
+
+const YIQ_TO_RGB = mat3x3<f32>(
+	vec3f(1.0,    1.0,    1.0),
+	vec3f(0.956, -0.272, -1.106),
+	vec3f(0.621, -0.647,  1.703),
+);
+
+// the signal carries gamma encoded colors
+fn sample_yiq(uv: vec2f) -> vec3f {
+	return RGB_TO_YIQ * pow(max(sample_viewport(screen_texture, texture_sampler, uv, view.viewport).rgb, vec3f(0.0)), vec3f(1.0 / GAMMA));
+}
+
+fn gaussian(x: f32, sigma: f32) -> f32 {
+	return exp(-(x * x) / (2.0 * sigma * sigma));
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	let sample_pos = floor(in.uv * settings.resolution);
+	let line_center = (sample_pos.y + 0.5) / settings.resolution.y;
+
+	let alpha = sample_viewport(screen_texture, texture_sampler, in.uv, view.viewport).a;
+
+	// the subcarrier is half a cycle off on every next line and, with dot crawl, every next frame
+	let frame_phase = fract(f32(globals.frame_count % 4096u) * settings.dot_crawl * 0.5) * TAU;
+	let line_phase = sample_pos.y * PI + frame_phase;
+
+	// a gaussian a half subcarrier cycle wide notches the subcarrier out of luma almost completely
+	let luma_sigma = settings.subcarrier_period * 0.5 / max(settings.luma_bandwidth, 0.001);
+	let chroma_sigma = settings.subcarrier_period / max(settings.chroma_bandwidth, 0.001);
+
+	var separate = vec3f(0.0);
+	var composite = vec3f(0.0);
+	var luma_weights = 0.0;
+	var chroma_weights = 0.0;
+
+	for (var tap = -TAPS; tap <= TAPS; tap++) {
+		let x = sample_pos.x + f32(tap);
+		let yiq = sample_yiq(vec2f((x + 0.5) / settings.resolution.x, line_center));
+
+		let phase = TAU * x / settings.subcarrier_period + line_phase;
+		let carrier = vec2f(cos(phase), sin(phase));
+
+		// encode
+		let signal = yiq.x + dot(yiq.yz, carrier);
+
+		let luma_weight = gaussian(f32(tap), luma_sigma);
+		let chroma_weight = gaussian(f32(tap), chroma_sigma);
+
+		separate += vec3f(yiq.x * luma_weight, yiq.yz * chroma_weight);
+
+		// decode, whatever the filters let through of the other component ends up as artifacts
+		composite += vec3f(signal * luma_weight, 2.0 * signal * carrier * chroma_weight);
+
+		luma_weights += luma_weight;
+		chroma_weights += chroma_weight;
+	}
+
+	let weights = vec3f(luma_weights, chroma_weights, chroma_weights);
+
+	let yiq = mix(separate, composite, settings.artifacts) / weights;
+
+	let rgb = pow(max(YIQ_TO_RGB * yiq, vec3f(0.0)), vec3f(GAMMA));
+
+	return vec4f(rgb, alpha);
+}
@@ -0,0 +1,110 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::pal::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+
+const PI = 3.14159265;
+const TAU = 6.28318531;
+const GAMMA = 2.2;
+
+// samples on each side of the decoded one
+const TAPS = 12;
+
+const RGB_TO_YUV = mat3x3<f32>(
+	vec3f(0.299, -0.147,  0.615),
+	vec3f(0.587, -0.289, -0.515),
+	vec3f(0.114,  0.436, -0.100),
+);
+
+const YUV_TO_RGB = mat3x3<f32>(
+	vec3f(1.0,    1.0,   1.0),
+	vec3f(0.0,   -0.395, 2.032),
+	vec3f(1.140, -0.581, 0.0),
+);
+
+// the signal carries gamma encoded colors
+fn sample_yuv(uv: vec2f) -> vec3f {
+	return RGB_TO_YUV * pow(max(sample_viewport(screen_texture, texture_sampler, uv, view.viewport).rgb, vec3f(0.0)), vec3f(1.0 / GAMMA));
+}
+
+fn gaussian(x: f32, sigma: f32) -> f32 {
+	return exp(-(x * x) / (2.0 * sigma * sigma));
+}
+
+// encodes the line around sample x and decodes yuv of that sample
+fn decode(x: f32, line: f32) -> vec3f {
+	let line_center = (line + 0.5) / settings.resolution.y;
+
+	// phase alternating line: V is inverted on every other line
+	let v_switch = select(1.0, -1.0, i32(line) % 2 == 1);
+
+	// the subcarrier is a quarter cycle off on every next line
+	let line_phase = line * PI * 0.5;
+
+	// a gaussian a half subcarrier cycle wide notches the subcarrier out of luma almost completely
+	let luma_sigma = settings.subcarrier_period * 0.5 / max(settings.luma_bandwidth, 0.001);
+	let chroma_sigma = settings.subcarrier_period / max(settings.chroma_bandwidth, 0.001);
+
+	var separate = vec3f(0.0);
+	var composite = vec3f(0.0);
+	var luma_weights = 0.0;
+	var chroma_weights = 0.0;
+
+	for (var tap = -TAPS; tap <= TAPS; tap++) {
+		let sample_x = x + f32(tap);
+		let yuv = sample_yuv(vec2f((sample_x + 0.5) / settings.resolution.x, line_center));
+
+		let phase = TAU * sample_x / settings.subcarrier_period + line_phase;
+
+		// encode
+		let signal = yuv.x + yuv.y * sin(phase) + v_switch * yuv.z * cos(phase);
+
+		// the decoder is off by the phase error, with V switched back the error rotates hue the opposite way on every other line
+		let decoder_phase = phase + settings.phase_error;
+		let carrier = vec2f(sin(decoder_phase), v_switch * cos(decoder_phase));
+
+		let luma_weight = gaussian(f32(tap), luma_sigma);
+		let chroma_weight = gaussian(f32(tap), chroma_sigma);
+
+		separate += vec3f(yuv.x * luma_weight, yuv.yz * chroma_weight);
+
+		// decode, whatever the filters let through of the other component ends up as artifacts
+		composite += vec3f(signal * luma_weight, 2.0 * signal * carrier * chroma_weight);
+
+		luma_weights += luma_weight;
+		chroma_weights += chroma_weight;
+	}
+
+	return mix(separate, composite, settings.artifacts) / vec3f(luma_weights, chroma_weights, chroma_weights);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	let sample_pos = floor(in.uv * settings.resolution);
+
+	let alpha = sample_viewport(screen_texture, texture_sampler, in.uv, view.viewport).a;
+
+	var yuv = decode(sample_pos.x, sample_pos.y);
+
+#ifdef DELAY_LINE
+	// opposite hue errors of neighbouring lines cancel out, leaving slightly desaturated color
+	let previous_line = decode(sample_pos.x, max(sample_pos.y - 1.0, 0.0));
+
+	yuv = vec3f(yuv.x, mix(yuv.yz, (yuv.yz + previous_line.yz) * 0.5, settings.delay_line));
+#endif
+
+	let rgb = pow(max(YUV_TO_RGB * yuv, vec3f(0.0)), vec3f(GAMMA));
+
+	return vec4f(rgb, alpha);
+}
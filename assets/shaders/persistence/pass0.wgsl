@@ -0,0 +1,38 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::persistence::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+@group(0) @binding(5) var history_texture: texture_2d<f32>;
+
+struct FragmentOutput {
+	@location(0) color: vec4f,
+	@location(1) history: vec4f,
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> FragmentOutput {
+	let sample = sample_viewport(screen_texture, texture_sampler, in.uv, view.viewport);
+
+	// exponential decay per channel, independent of the frame rate
+	let fade = exp(-globals.delta_time / max(settings.decay_time, vec3f(0.0001)));
+
+	// a phosphor stays lit by the beam or keeps glowing from the previous frames, whichever is brighter
+	let phosphor = max(sample.rgb, sample_viewport(history_texture, texture_sampler, in.uv, view.viewport).rgb * fade);
+
+	var out: FragmentOutput;
+	out.color = vec4f(mix(sample.rgb, phosphor, settings.intensity), sample.a);
+	out.history = vec4f(phosphor, 1.0);
+
+	return out;
+}
@@ -0,0 +1,71 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::power::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+
+// IMPORTANT! keep this in sync with CrtPowerState in src/power/mod.rs
+const STATE_TURNING_OFF = 1u;
+const STATE_TURNING_ON = 3u;
+
+// thickness of the line and size of the dot relative to the screen
+const BEAM = 0.004;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	let p = settings.progress;
+	let centered = in.uv - 0.5;
+
+	// picture size relative to the screen, brightness of the picture and a washed out white it blends into
+	var scale = vec2f(0.0);
+	var brightness = 0.0;
+	var wash = 0.0;
+	var glow = 0.0;
+
+	if settings.state == STATE_TURNING_OFF {
+		// collapse into a line, then into a dot that fades away
+		let collapse = smoothstep(0.0, 0.45, p);
+		let shrink = smoothstep(0.45, 0.8, p);
+		let fade = 1.0 - smoothstep(0.8, 1.0, p);
+
+		scale = vec2f(mix(1.0, BEAM, shrink), mix(1.0, BEAM, collapse));
+
+		// the same beam energy in fewer lines
+		brightness = (1.0 + 4.0 * collapse + 4.0 * shrink) * fade;
+		wash = collapse;
+		glow = collapse * fade;
+	} else if settings.state == STATE_TURNING_ON {
+		// a flash of a line expanding into a dim picture that warms up
+		let expand = smoothstep(0.0, 0.35, p);
+		let warm = smoothstep(0.1, 1.0, p);
+
+		scale = vec2f(1.0, mix(BEAM, 1.0, expand));
+
+		brightness = mix(0.05, 1.0, warm) + 3.0 * (1.0 - expand);
+		wash = 1.0 - expand;
+		glow = 1.0 - expand;
+	}
+
+	let sample = sample_viewport(screen_texture, texture_sampler, centered / max(scale, vec2f(BEAM)) + 0.5, view.viewport);
+
+	let inside = all(abs(centered) <= scale * 0.5);
+
+	var color = mix(sample.rgb, vec3f(1.0), wash) * brightness;
+	color = select(vec3f(0.0), color, inside);
+
+	// soft halo around the line or dot
+	let halo = length(centered / (scale * 0.5 + 0.02));
+	color += vec3f(exp(-halo * 3.0) * glow * 0.5);
+
+	return vec4f(color, sample.a);
+}
@@ -0,0 +1,47 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::scanline::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+
+// gaussian beam profile, width is the full width at half maximum in lines
+fn beam(distance: f32, width: vec3f) -> vec3f {
+	let sigma = max(width, vec3f(0.0001)) / 2.3548;
+	return exp(-(distance * distance) / (2.0 * sigma * sigma));
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	let line = in.uv.y * settings.line_count;
+
+	// every line is lit by the beam of its own and, for wide beams, the one next to it.
+	// the beam is sampled at the center of its line so that it has the same width along the whole line
+	let line_center = (floor(line) + 0.5) / settings.line_count;
+	let distance = fract(line) - 0.5;
+	let neighbour_center = line_center + sign(distance) / settings.line_count;
+
+	let sample = sample_viewport(screen_texture, texture_sampler, in.uv, view.viewport);
+	let own_color = sample_viewport(screen_texture, texture_sampler, vec2f(in.uv.x, line_center), view.viewport).rgb;
+	let neighbour_color = sample_viewport(screen_texture, texture_sampler, vec2f(in.uv.x, neighbour_center), view.viewport).rgb;
+
+	// bright pixels widen the beam, dark ones narrow it
+	let width_range = settings.beam_width_max - settings.beam_width_min;
+	let own_width = settings.beam_width_min + saturate(own_color) * width_range;
+	let neighbour_width = settings.beam_width_min + saturate(neighbour_color) * width_range;
+
+	let lit = own_color * beam(abs(distance), own_width) + neighbour_color * beam(1.0 - abs(distance), neighbour_width);
+
+	let color = mix(sample.rgb, lit * settings.brightness, settings.intensity);
+
+	return vec4<f32>(color, sample.a);
+}
@@ -0,0 +1,51 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::vhs::CrtSettings
+#import bevy_crt_galore::vhs_tape::{hash, value_noise, tracking_band}
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	let line = floor(in.uv.y * settings.resolution.y);
+	let frame = f32(globals.frame_count % 65536u);
+
+	// horizontal displacement in screen widths
+	var offset = 0.0;
+
+#ifdef JITTER
+	offset += (hash(vec2f(line, frame)) - 0.5) * settings.jitter / settings.resolution.x;
+#endif
+
+#ifdef TRACKING
+	let band = tracking_band(in.uv.y, globals.time, settings.tracking_speed);
+	offset += band * value_noise(line * 0.1 + globals.time * 20.0, 7.0) * settings.tracking_error;
+#endif
+
+#ifdef HEAD_SWITCHING
+	// the last lines before the heads switch get pulled sideways
+	let head_switch_start = 1.0 - settings.head_switching;
+	if in.uv.y > head_switch_start {
+		let k = (in.uv.y - head_switch_start) / settings.head_switching;
+		offset += k * k * 0.04 + (hash(vec2f(line, frame + 0.5)) - 0.5) * 0.01;
+	}
+#endif
+
+	let uv = vec2f(in.uv.x + offset, in.uv.y);
+	let sample = sample_viewport(screen_texture, texture_sampler, uv, view.viewport);
+
+	// lines pulled past the edge leave black behind
+	let outside = uv.x < 0.0 || uv.x > 1.0;
+
+	return select(sample, vec4f(0.0, 0.0, 0.0, sample.a), outside);
+}
@@ -0,0 +1,72 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::vhs::CrtSettings
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+
+const GAMMA = 2.2;
+
+// samples on each side, half a luma (or chroma) sample apart
+const TAPS = 6;
+
+const RGB_TO_YIQ = mat3x3<f32>(
+	vec3f(0.299,  0.596,  0.211),
+	vec3f(0.587, -0.274, -0.523),
+	vec3f(0.114, -0.322,  0.312),
+);
+
+const YIQ_TO_RGB = mat3x3<f32>(
+	vec3f(1.0,    1.0,    1.0),
+	vec3f(0.956, -0.272, -1.106),
+	vec3f(0.621, -0.647,  1.703),
+);
+
+// tape stores gamma encoded colors
+fn sample_yiq(uv: vec2f) -> vec3f {
+	return RGB_TO_YIQ * pow(max(sample_viewport(screen_texture, texture_sampler, uv, view.viewport).rgb, vec3f(0.0)), vec3f(1.0 / GAMMA));
+}
+
+fn gaussian(x: f32, sigma: f32) -> f32 {
+	return exp(-(x * x) / (2.0 * sigma * sigma));
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	let line_center = (floor(in.uv.y * settings.resolution.y) + 0.5) / settings.resolution.y;
+
+	let luma_step = 0.5 / settings.resolution.x;
+	let chroma_step = 0.5 / max(settings.chroma_resolution, 1.0);
+
+	// chroma lags behind luma
+	let chroma_center = in.uv.x - settings.chroma_delay / settings.resolution.x;
+
+	var luma = 0.0;
+	var chroma = vec2f(0.0);
+	var weights = 0.0;
+
+	for (var tap = -TAPS; tap <= TAPS; tap++) {
+		// two taps per sample, so one sample wide
+		let weight = gaussian(f32(tap), 2.0);
+
+		luma += sample_yiq(vec2f(in.uv.x + f32(tap) * luma_step, line_center)).x * weight;
+		chroma += sample_yiq(vec2f(chroma_center + f32(tap) * chroma_step, line_center)).yz * weight;
+
+		weights += weight;
+	}
+
+	let yiq = vec3f(luma, chroma) / weights;
+
+	let rgb = pow(max(YIQ_TO_RGB * yiq, vec3f(0.0)), vec3f(GAMMA));
+
+	return vec4f(rgb, sample_viewport(screen_texture, texture_sampler, in.uv, view.viewport).a);
+}
@@ -0,0 +1,52 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+#import bevy_render::globals::Globals
+#import bevy_render::view::View
+#import bevy_crt_galore::common::sample_viewport
+#import bevy_crt_galore::vhs::CrtSettings
+#import bevy_crt_galore::vhs_tape::{hash, value_noise, tracking_band}
+
+alias vec2f = vec2<f32>;
+alias vec3f = vec3<f32>;
+alias vec4f = vec4<f32>;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtSettings;
+@group(0) @binding(3) var<uniform> globals: Globals;
+@group(0) @binding(4) var<uniform> view: View;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+	let sample = sample_viewport(screen_texture, texture_sampler, in.uv, view.viewport);
+
+	var color = sample.rgb;
+
+	let sample_pos = floor(in.uv * settings.resolution);
+	let frame = f32(globals.frame_count % 65536u);
+
+#ifdef NOISE
+	// bands of stronger noise slowly rolling through the picture
+	let band = saturate(value_noise(in.uv.y * 3.0 - globals.time * 0.4, 3.0) * 0.5 + 0.5);
+	let snow = hash(vec2f(sample_pos.x * 0.37 + sample_pos.y, frame)) - 0.5;
+	color += snow * settings.noise * (0.1 + band * band * 0.4);
+#endif
+
+#ifdef TRACKING
+	// white specks where the heads lose the tracks
+	let tracking = tracking_band(in.uv.y, globals.time, settings.tracking_speed) * saturate(settings.tracking_error * 20.0);
+	let speck = step(1.0 - tracking * 0.3, hash(vec2f(floor(sample_pos.x / 4.0), sample_pos.y + frame * 13.0)));
+	color = mix(color, vec3f(1.0), speck * 0.8);
+#endif
+
+#ifdef DROPOUTS
+	// a dropout is a short bright streak on a random line lasting a single frame
+	if hash(vec2f(sample_pos.y, frame * 0.61)) < settings.dropouts * 0.01 {
+		let start = hash(vec2f(sample_pos.y + 0.3, frame));
+		let streak_length = 0.02 + hash(vec2f(sample_pos.y + 0.7, frame)) * 0.15;
+		let streak = step(start, in.uv.x) * step(in.uv.x, start + streak_length);
+		color = mix(color, vec3f(0.9), streak);
+	}
+#endif
+
+	return vec4f(max(color, vec3f(0.0)), sample.a);
+}
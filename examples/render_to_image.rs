@@ -0,0 +1,122 @@
+use bevy::{
+	prelude::*,
+	render::{
+		camera::RenderTarget,
+		render_resource::{Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages},
+		view::RenderLayers,
+	},
+};
+use bevy_crt_galore::*;
+
+fn main() {
+	App::new()
+		.add_plugins((
+			DefaultPlugins,
+			CrtGalorePlugin,
+		))
+		.add_systems(Startup, setup)
+		.add_systems(Update, rotate)
+		.run();
+}
+
+/// Renders a cube into an image through an ldr camera with CRT effects and shows it on an in-world screen
+fn setup(
+	mut commands	: Commands,
+	mut meshes		: ResMut<Assets<Mesh>>,
+	mut materials	: ResMut<Assets<StandardMaterial>>,
+	mut images		: ResMut<Assets<Image>>,
+) {
+	let size = Extent3d {
+		width: 320,
+		height: 240,
+		..default()
+	};
+
+	let mut image = Image {
+		texture_descriptor: TextureDescriptor {
+			label: None,
+			size,
+			dimension: TextureDimension::D2,
+			format: TextureFormat::Bgra8UnormSrgb,
+			mip_level_count: 1,
+			sample_count: 1,
+			usage: TextureUsages::TEXTURE_BINDING
+				| TextureUsages::COPY_DST
+				| TextureUsages::RENDER_ATTACHMENT,
+			view_formats: &[],
+		},
+		..default()
+	};
+
+	// fill image.data with zeroes
+	image.resize(size);
+
+	let image_handle = images.add(image);
+
+	let screen_layer = RenderLayers::layer(1);
+
+	// offscreen camera, not hdr on purpose
+	commands.spawn((
+		Camera3dBundle {
+			camera: Camera {
+				order: -1,
+				target: RenderTarget::Image(image_handle.clone()),
+				clear_color: ClearColorConfig::Custom(Color::rgb(0.1, 0.1, 0.2)),
+				..default()
+			},
+			transform: Transform::from_translation(Vec3::new(0.0, 0.0, 5.0))
+				.looking_at(Vec3::default(), Vec3::Y),
+			..default()
+		},
+		CrtXorSettings::MILD,
+		screen_layer,
+	));
+
+	// cube seen only by the offscreen camera
+	commands.spawn((
+		PbrBundle {
+			mesh: meshes.add(Mesh::from(Cuboid::default())),
+			material: materials.add(Color::rgb(0.8, 0.7, 0.6)),
+			..default()
+		},
+		Rotates,
+		screen_layer,
+	));
+
+	commands.spawn((
+		PointLightBundle {
+			transform: Transform::from_translation(Vec3::new(0.0, 0.0, 10.0)),
+			..default()
+		},
+		screen_layer,
+	));
+
+	// in-world screen showing the offscreen camera image
+	commands.spawn(PbrBundle {
+		mesh: meshes.add(Mesh::from(Rectangle::new(4.0, 3.0))),
+		material: materials.add(StandardMaterial {
+			base_color_texture: Some(image_handle),
+			unlit: true,
+			..default()
+		}),
+		..default()
+	});
+
+	// main camera
+	commands.spawn(Camera3dBundle {
+		transform: Transform::from_translation(Vec3::new(2.0, 1.0, 6.0))
+			.looking_at(Vec3::default(), Vec3::Y),
+		..default()
+	});
+}
+
+#[derive(Component)]
+struct Rotates;
+
+/// Rotates any entity around the x and z axis
+fn rotate(time: Res<Time>, mut query: Query<&mut Transform, With<Rotates>>) {
+	for mut transform in &mut query {
+		transform.rotate_x(0.55 * time.delta_seconds());
+		transform.rotate_z(0.15 * time.delta_seconds());
+	}
+}
@@ -0,0 +1,190 @@
+use bevy::{
+	asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+	prelude::*,
+	utils::BoxedFuture,
+};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// How the bezel image is fitted into a camera viewport of a different aspect ratio
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CrtBezelFit {
+	/// Whole bezel visible, black bars on the sides that don't fit
+	#[default]
+	Contain,
+	/// Bezel fills the viewport, the sides that don't fit are cropped
+	Cover,
+	/// Bezel is stretched to the viewport
+	Stretch,
+}
+
+impl CrtBezelFit {
+	/// Scale of viewport uv around the center that turns it into bezel image uv
+	pub(crate) fn uv_scale(&self, viewport_aspect: f32, image_aspect: f32) -> Vec2 {
+		let ratio = viewport_aspect / image_aspect;
+
+		match self {
+			CrtBezelFit::Contain	=> Vec2::new(ratio.max(1.0), (1.0 / ratio).max(1.0)),
+			CrtBezelFit::Cover		=> Vec2::new(ratio.min(1.0), (1.0 / ratio).min(1.0)),
+			CrtBezelFit::Stretch	=> Vec2::ONE,
+		}
+	}
+}
+
+/// Bezel artwork and where the picture goes inside it, loaded from `*.bezel.ron` files:
+/// ```ron
+/// (
+///     // relative to the layout file
+///     image: "arcade.png",
+///     // picture area in image uv: min x, min y, max x, max y
+///     screen_rect: (0.12, 0.1, 0.88, 0.86),
+///     fit: Contain,
+/// )
+/// ```
+/// The picture shows through where the image is transparent.
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct CrtBezelLayout {
+	pub image		: Handle<Image>,
+	pub screen_rect	: Rect,
+	pub fit			: CrtBezelFit,
+}
+
+#[derive(Deserialize, Debug)]
+struct CrtBezelLayoutFile {
+	image		: String,
+	screen_rect	: (f32, f32, f32, f32),
+	#[serde(default)]
+	fit			: CrtBezelFit,
+}
+
+impl CrtBezelLayoutFile {
+	fn parse(bytes: &[u8]) -> Result<Self, ron::error::SpannedError> {
+		ron::de::from_bytes(bytes)
+	}
+
+	fn screen_rect(&self) -> Rect {
+		let (min_x, min_y, max_x, max_y) = self.screen_rect;
+
+		Rect::new(min_x, min_y, max_x, max_y)
+	}
+
+	// relative to the layout file
+	fn image_path(&self, layout_path: &Path) -> PathBuf {
+		match layout_path.parent() {
+			Some(directory) => directory.join(&self.image),
+			None => self.image.clone().into(),
+		}
+	}
+}
+
+#[derive(Default)]
+pub struct CrtBezelLayoutLoader;
+
+#[derive(Debug, Error)]
+pub enum CrtBezelLayoutLoaderError {
+	#[error("could not read bezel layout: {0}")]
+	Io(#[from] std::io::Error),
+	#[error("could not parse bezel layout: {0}")]
+	Ron(#[from] ron::error::SpannedError),
+}
+
+impl AssetLoader for CrtBezelLayoutLoader {
+	type Asset = CrtBezelLayout;
+	type Settings = ();
+	type Error = CrtBezelLayoutLoaderError;
+
+	fn load<'a>(
+		&'a self,
+		reader			: &'a mut Reader,
+		_settings		: &'a (),
+		load_context	: &'a mut LoadContext,
+	) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+		Box::pin(async move {
+			let mut bytes = Vec::new();
+			reader.read_to_end(&mut bytes).await?;
+
+			let file = CrtBezelLayoutFile::parse(&bytes)?;
+
+			let image_path = file.image_path(load_context.path());
+
+			Ok(CrtBezelLayout {
+				image		: load_context.load(image_path),
+				screen_rect	: file.screen_rect(),
+				fit			: file.fit,
+			})
+		})
+	}
+
+	fn extensions(&self) -> &[&str] {
+		&["bezel.ron"]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const ARCADE : &str = r#"(
+		// relative to the layout file
+		image: "arcade.png",
+		screen_rect: (0.12, 0.1, 0.88, 0.86),
+		fit: Cover,
+	)"#;
+
+	#[test]
+	fn parses_layout() {
+		let file = CrtBezelLayoutFile::parse(ARCADE.as_bytes()).unwrap();
+
+		assert_eq!(file.image, "arcade.png");
+		assert_eq!(file.screen_rect(), Rect::new(0.12, 0.1, 0.88, 0.86));
+		assert_eq!(file.fit, CrtBezelFit::Cover);
+		assert_eq!(file.image_path(Path::new("bezels/arcade.bezel.ron")), Path::new("bezels/arcade.png"));
+	}
+
+	#[test]
+	fn fit_defaults_to_contain() {
+		let file = CrtBezelLayoutFile::parse(br#"(image: "tv.png", screen_rect: (0.0, 0.0, 1.0, 1.0))"#).unwrap();
+
+		assert_eq!(file.fit, CrtBezelFit::Contain);
+	}
+
+	#[test]
+	fn rejects_malformed_layout() {
+		assert!(CrtBezelLayoutFile::parse(br#"(image: "tv.png")"#).is_err());
+		assert!(CrtBezelLayoutFile::parse(br#"(image: "tv.png", screen_rect: (0.0, 0.0, 1.0, 1.0), fit: Zoom)"#).is_err());
+	}
+
+	#[test]
+	fn fit_matching_aspect_is_identity() {
+		for fit in [CrtBezelFit::Contain, CrtBezelFit::Cover, CrtBezelFit::Stretch] {
+			assert_eq!(fit.uv_scale(4.0 / 3.0, 4.0 / 3.0), Vec2::ONE);
+		}
+	}
+
+	#[test]
+	fn fit_contain_adds_bars() {
+		// 4:3 bezel in a 16:9 window, bars on the left and right
+		let wide = CrtBezelFit::Contain.uv_scale(16.0 / 9.0, 4.0 / 3.0);
+		assert!((wide - Vec2::new(4.0 / 3.0, 1.0)).length() < 1e-5);
+
+		// 4:3 bezel in a portrait window, bars at the top and bottom
+		let tall = CrtBezelFit::Contain.uv_scale(9.0 / 16.0, 4.0 / 3.0);
+		assert!((tall - Vec2::new(1.0, 64.0 / 27.0)).length() < 1e-5);
+	}
+
+	#[test]
+	fn fit_cover_crops() {
+		let wide = CrtBezelFit::Cover.uv_scale(16.0 / 9.0, 4.0 / 3.0);
+		assert!((wide - Vec2::new(1.0, 0.75)).length() < 1e-5);
+
+		let tall = CrtBezelFit::Cover.uv_scale(9.0 / 16.0, 4.0 / 3.0);
+		assert!((tall - Vec2::new(27.0 / 64.0, 1.0)).length() < 1e-5);
+	}
+
+	#[test]
+	fn fit_stretch_ignores_aspect() {
+		assert_eq!(CrtBezelFit::Stretch.uv_scale(16.0 / 9.0, 4.0 / 3.0), Vec2::ONE);
+		assert_eq!(CrtBezelFit::Stretch.uv_scale(9.0 / 16.0, 4.0 / 3.0), Vec2::ONE);
+	}
+}
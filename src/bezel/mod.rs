@@ -0,0 +1,124 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		camera::CameraUpdateSystem,
+		extract_component::{ExtractComponent, ExtractComponentPlugin},
+		render_resource::{ShaderRef, ShaderType},
+	},
+};
+
+use super::*;
+
+mod layout;
+
+pub use layout::*;
+
+// $ uuidgen
+pub const BEZEL_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0x93fe25719b894a32ac39b87c06393180u128);
+pub const BEZEL_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0xfdb05bad21364befbfa9e8cec274a33eu128);
+
+pub struct BezelCrtPlugin;
+
+impl Plugin for BezelCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, BEZEL_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, BEZEL_PASS0_SHADER_HANDLE, "../../assets/shaders/bezel/pass0.wgsl", Shader::from_wgsl);
+
+		app
+			.init_asset::<CrtBezelLayout>()
+			.init_asset_loader::<CrtBezelLayoutLoader>()
+			.add_plugins((
+				ExtractComponentPlugin::<CrtBezelImage>::default(),
+				CrtEffectPlugin::<CrtBezelSettings>::default(),
+			))
+			// viewport size is updated by the camera system
+			.add_systems(PostUpdate, update_crt_bezels.after(CameraUpdateSystem));
+	}
+}
+
+/// Frames the picture of the camera with bezel artwork described by a [`CrtBezelLayout`].
+/// Put [`CrtBezelSettings`] last in a [`CrtChain`] so that curvature and the rest land inside the screen rect.
+/// ```ignore
+/// commands.spawn((
+///     Camera3dBundle::default(),
+///     CrtXorSettings::MILD,
+///     CrtBezel(asset_server.load("bezels/arcade.bezel.ron")),
+///     CrtChain::new().then::<CrtXorSettings>().then::<CrtBezelSettings>(),
+/// ));
+/// ```
+#[derive(Component, Clone, Debug)]
+pub struct CrtBezel(pub Handle<CrtBezelLayout>);
+
+/// Kept in sync with [`CrtBezel`] once its layout and image are loaded, not meant to be inserted by hand.
+// IMPORTANT! keep this in sync with src/bezel/settings.wgsl
+#[derive(Component, Clone, Copy, PartialEq, ExtractComponent, ShaderType)]
+pub struct CrtBezelSettings {
+	pub screen_rect		: Vec4,		// Picture area in bezel image uv, min xy and max xy
+	pub uv_scale		: Vec2,		// Viewport uv to bezel image uv around the center, see CrtBezelFit
+}
+
+#[derive(Component, Clone, PartialEq, ExtractComponent)]
+pub(crate) struct CrtBezelImage(Handle<Image>);
+
+#[allow(clippy::type_complexity)]
+fn update_crt_bezels(
+	mut commands	: Commands,
+	mut removed		: RemovedComponents<CrtBezel>,
+	mut bezels		: Query<(Entity, &CrtBezel, &Camera, Option<&mut CrtBezelSettings>, Option<&mut CrtBezelImage>)>,
+	layouts			: Res<Assets<CrtBezelLayout>>,
+	images			: Res<Assets<Image>>,
+) {
+	for entity in removed.read() {
+		if let Some(mut entity_commands) = commands.get_entity(entity) {
+			entity_commands.remove::<(CrtBezelSettings, CrtBezelImage)>();
+		}
+	}
+
+	for (entity, bezel, camera, settings, image_handle) in &mut bezels {
+		let loaded = layouts.get(&bezel.0).and_then(|layout| images.get(&layout.image).map(|image| (layout, image)));
+
+		let Some((layout, image)) = loaded else {
+			if settings.is_some() || image_handle.is_some() {
+				commands.entity(entity).remove::<(CrtBezelSettings, CrtBezelImage)>();
+			}
+			continue;
+		};
+
+		// not known until the camera is rendered for the first time
+		let Some(viewport_size) = camera.physical_viewport_size() else { continue };
+
+		let viewport_aspect = viewport_size.x as f32 / viewport_size.y.max(1) as f32;
+		let rect = layout.screen_rect;
+
+		let new_settings = CrtBezelSettings {
+			screen_rect		: Vec4::new(rect.min.x, rect.min.y, rect.max.x, rect.max.y),
+			uv_scale		: layout.fit.uv_scale(viewport_aspect, image.aspect_ratio().into()),
+		};
+
+		// only touched when something changed so that extraction and bind groups stay cached
+		match settings {
+			Some(mut settings) => { settings.set_if_neq(new_settings); },
+			None => { commands.entity(entity).insert(new_settings); },
+		}
+
+		let new_image = CrtBezelImage(layout.image.clone());
+
+		match image_handle {
+			Some(mut image_handle) => { image_handle.set_if_neq(new_image); },
+			None => { commands.entity(entity).insert(new_image); },
+		}
+	}
+}
+
+impl CrtEffect for CrtBezelSettings {
+	const NAME : &'static str = "bezel";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![BEZEL_PASS0_SHADER_HANDLE.into()]
+	}
+
+	fn aux_image(world: &World, view_entity: Entity) -> Option<AssetId<Image>> {
+		world.get::<CrtBezelImage>(view_entity).map(|image| image.0.id())
+	}
+}
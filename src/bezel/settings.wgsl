@@ -0,0 +1,6 @@
+#define_import_path bevy_crt_galore::bezel
+
+struct CrtSettings {
+	screen_rect			: vec4<f32>,	// Picture area in bezel image uv, min xy and max xy
+	uv_scale			: vec2<f32>,	// Viewport uv to bezel image uv around the center, see CrtBezelFit
+}
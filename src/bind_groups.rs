@@ -0,0 +1,84 @@
+use std::{marker::PhantomData, sync::Mutex};
+
+use bevy::{
+	prelude::*,
+	render::render_resource::{BindGroup, BufferId, TextureViewId},
+	utils::HashMap,
+};
+
+/// Everything a CRT bind group is built from. The sampler and layout never change so they're not part of it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct CrtBindGroupKey {
+	pub source		: TextureViewId,
+	pub settings	: BufferId,
+	pub globals		: BufferId,
+	pub view		: BufferId,
+	pub history		: TextureViewId,
+	pub aux			: TextureViewId,
+}
+
+impl CrtBindGroupKey {
+	fn same_buffers(&self, other: &Self) -> bool {
+		self.settings == other.settings && self.globals == other.globals && self.view == other.view
+	}
+}
+
+struct CachedBindGroup {
+	key			: CrtBindGroupKey,
+	bind_group	: BindGroup,
+}
+
+/// Per view bind groups of the family with settings `T`.
+/// Post processing ping-pongs between two main textures and the history between its two textures
+/// so every view keeps at most four bind groups.
+#[derive(Resource)]
+pub(crate) struct CrtBindGroupCache<T> {
+	views	: Mutex<HashMap<Entity, Vec<CachedBindGroup>>>,
+	marker	: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for CrtBindGroupCache<T> {
+	fn default() -> Self {
+		Self {
+			views	: Mutex::new(HashMap::default()),
+			marker	: PhantomData,
+		}
+	}
+}
+
+impl<T> CrtBindGroupCache<T> {
+	pub(crate) fn get_or_create(
+		&self,
+		view_entity	: Entity,
+		key			: CrtBindGroupKey,
+		create		: impl FnOnce() -> BindGroup,
+	) -> BindGroup {
+		let mut views = self.views.lock().unwrap();
+
+		let cached = views.entry(view_entity).or_default();
+
+		if let Some(cached) = cached.iter().find(|cached| cached.key == key) {
+			return cached.bind_group.clone();
+		}
+
+		// a buffer got reallocated or the main textures were recreated (resize, hdr toggle)
+		cached.retain(|cached| cached.key.same_buffers(&key));
+		if cached.len() >= 4 {
+			cached.remove(0);
+		}
+
+		let bind_group = create();
+
+		cached.push(CachedBindGroup { key, bind_group: bind_group.clone() });
+
+		bind_group
+	}
+}
+
+/// Drops bind groups of views that no longer have the family settings so that they don't keep old textures alive
+pub(crate) fn prune_crt_bind_groups<T: Component>(
+	mut cache	: ResMut<CrtBindGroupCache<T>>,
+	views		: Query<(), With<T>>,
+) {
+	cache.views.get_mut().unwrap().retain(|view_entity, _| views.contains(*view_entity));
+}
@@ -0,0 +1,167 @@
+use std::any::TypeId;
+
+use bevy::{
+	ecs::query::QueryItem,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_graph::{NodeRunError, RenderGraphContext, RenderLabel, ViewNode},
+		renderer::RenderContext,
+	},
+};
+
+use super::*;
+
+/// Explicit order in which CRT families are applied on a camera, first to last.
+/// Only the listed families are run. Without this component every family present on the
+/// camera runs in the order its plugin was added: Endesga, Xor, Gavlig, Scanline and so on.
+/// ```ignore
+/// commands.spawn((
+///     Camera3dBundle::default(),
+///     CrtXorSettings::MILD,
+///     CrtEndesgaSettings::MILD,
+///     CrtChain::new().then::<CrtXorSettings>().then::<CrtEndesgaSettings>(),
+/// ));
+/// ```
+#[derive(Component, ExtractComponent, Clone, Default, Debug)]
+pub struct CrtChain {
+	families : Vec<TypeId>,
+}
+
+impl CrtChain {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Appends family with settings component `T` to the end of the chain.
+	/// Families whose plugin wasn't added to the app are skipped.
+	pub fn then<T: CrtEffect>(mut self) -> Self {
+		self.families.push(TypeId::of::<T>());
+		self
+	}
+}
+
+/// Renders all passes of one family for the given view, does nothing if the view doesn't have the family settings.
+pub(crate) type CrtRunFn = for<'w> fn(&'w World, Entity, &mut RenderContext<'w>) -> Result<(), NodeRunError>;
+
+/// Render world registry of CRT families in registration order.
+#[derive(Resource, Default)]
+pub(crate) struct CrtFamilies {
+	families : Vec<(TypeId, CrtRunFn)>,
+}
+
+impl CrtFamilies {
+	/// Registered families in the order of the chain, or all of them in registration order without one
+	fn in_order<'a>(&'a self, chain: Option<&'a CrtChain>) -> impl Iterator<Item = &'a (TypeId, CrtRunFn)> + 'a {
+		let chained = chain.into_iter().flat_map(move |chain| {
+			chain.families.iter().filter_map(move |family| self.families.iter().find(|(id, _)| id == family))
+		});
+
+		let registered = self.families.iter().filter(move |_| chain.is_none());
+
+		chained.chain(registered)
+	}
+}
+
+pub(crate) fn register_crt_family<T: CrtEffect>(render_app: &mut App, run: CrtRunFn) {
+	render_app
+		.init_resource::<CrtFamilies>()
+		.world
+		.resource_mut::<CrtFamilies>()
+		.families
+		.push((TypeId::of::<T>(), run));
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub(crate) struct CrtGaloreLabel(pub CrtPlacement);
+
+/// Runs CRT families of a view in the order defined by its [`CrtChain`].
+#[derive(Clone)]
+pub(crate) struct CrtGaloreNode {
+	pub placement : CrtPlacement,
+}
+
+impl ViewNode for CrtGaloreNode {
+	type ViewQuery = (
+		Option<&'static CrtPlacement>,
+		Option<&'static CrtChain>,
+	);
+
+	fn run<'w>(
+		&self,
+		graph: &mut RenderGraphContext,
+		render_context: &mut RenderContext<'w>,
+		(placement, chain): QueryItem<'w, Self::ViewQuery>,
+		world: &'w World,
+	) -> Result<(), NodeRunError> {
+		if placement.copied().unwrap_or_default() != self.placement {
+			return Ok(());
+		}
+
+		let view_entity = graph.view_entity();
+
+		for (_, run) in world.resource::<CrtFamilies>().in_order(chain) {
+			run(world, view_entity, render_context)?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn run_nothing<'w>(_world: &'w World, _view_entity: Entity, _render_context: &mut RenderContext<'w>) -> Result<(), NodeRunError> {
+		Ok(())
+	}
+
+	fn family<T: CrtEffect>() -> (TypeId, CrtRunFn) {
+		(TypeId::of::<T>(), run_nothing)
+	}
+
+	fn families() -> CrtFamilies {
+		CrtFamilies {
+			families : vec![
+				family::<CrtEndesgaSettings>(),
+				family::<CrtXorSettings>(),
+				family::<CrtScanlineSettings>(),
+			],
+		}
+	}
+
+	fn order(families: &CrtFamilies, chain: Option<&CrtChain>) -> Vec<TypeId> {
+		families.in_order(chain).map(|(id, _)| *id).collect()
+	}
+
+	#[test]
+	fn registration_order_without_chain() {
+		assert_eq!(order(&families(), None), vec![
+			TypeId::of::<CrtEndesgaSettings>(),
+			TypeId::of::<CrtXorSettings>(),
+			TypeId::of::<CrtScanlineSettings>(),
+		]);
+	}
+
+	#[test]
+	fn chain_order() {
+		let chain = CrtChain::new().then::<CrtScanlineSettings>().then::<CrtEndesgaSettings>();
+
+		assert_eq!(order(&families(), Some(&chain)), vec![
+			TypeId::of::<CrtScanlineSettings>(),
+			TypeId::of::<CrtEndesgaSettings>(),
+		]);
+	}
+
+	#[test]
+	fn chain_skips_unregistered_families() {
+		let chain = CrtChain::new().then::<CrtGavligSettings>().then::<CrtXorSettings>();
+
+		assert_eq!(order(&families(), Some(&chain)), vec![TypeId::of::<CrtXorSettings>()]);
+	}
+
+	#[test]
+	fn empty_chain_runs_nothing() {
+		assert!(order(&families(), Some(&CrtChain::new())).is_empty());
+	}
+}
@@ -0,0 +1,15 @@
+#define_import_path bevy_crt_galore::common
+
+// Converts uv local to the camera viewport into uv of the whole screen texture.
+// Clamped to the viewport so that distorted lookups never sample neighbouring split-screen panes.
+fn viewport_to_texture_uv(uv: vec2<f32>, viewport: vec4<f32>, texture_size: vec2<f32>) -> vec2<f32> {
+	let half_texel = 0.5 / viewport.zw;
+	return (viewport.xy + clamp(uv, half_texel, 1.0 - half_texel) * viewport.zw) / texture_size;
+}
+
+// Samples a texture covering the whole screen, like the main texture or the history, with uv local to the camera viewport.
+// Passes are drawn into the camera viewport so their in.uv is local to it.
+fn sample_viewport(source: texture_2d<f32>, source_sampler: sampler, uv: vec2<f32>, viewport: vec4<f32>) -> vec4<f32> {
+	let texture_size = vec2<f32>(textureDimensions(source));
+	return textureSample(source, source_sampler, viewport_to_texture_uv(uv, viewport, texture_size));
+}
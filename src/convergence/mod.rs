@@ -0,0 +1,127 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_resource::{ShaderRef, ShaderType},
+	},
+};
+
+use std::ops;
+
+use super::*;
+
+// $ uuidgen
+pub const CONVERGENCE_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0x26c9e2279553470a8a5d4ee7fc1d386bu128);
+pub const CONVERGENCE_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0x9fca56d098de4315a0709c16fb510319u128);
+
+pub struct ConvergenceCrtPlugin;
+
+impl Plugin for ConvergenceCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, CONVERGENCE_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, CONVERGENCE_PASS0_SHADER_HANDLE, "../../assets/shaders/convergence/pass0.wgsl", Shader::from_wgsl);
+
+		app.add_plugins(CrtEffectPlugin::<CrtConvergenceSettings>::default());
+	}
+}
+
+/// Misregistration of the three electron beams. Static offsets shift a channel over the whole screen,
+/// dynamic offsets grow from zero in the center to their full value in the corners, mirrored per quadrant,
+/// so positive x of a dynamic offset pulls the channel outwards on both sides.
+// IMPORTANT! keep this in sync with src/convergence/settings.wgsl
+#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct CrtConvergenceSettings {
+	pub red_static		: Vec2,		// Red offset over the whole screen in texels
+	pub green_static	: Vec2,		// Green offset over the whole screen in texels
+	pub blue_static		: Vec2,		// Blue offset over the whole screen in texels
+	pub red_dynamic		: Vec2,		// Additional red offset in the corners in texels, zero in the center
+	pub green_dynamic	: Vec2,		// Additional green offset in the corners in texels, zero in the center
+	pub blue_dynamic	: Vec2,		// Additional blue offset in the corners in texels, zero in the center
+}
+
+impl CrtConvergenceSettings {
+	pub const STRONG : Self = Self {
+		red_static		: Vec2::new(1.0, 0.0),
+		green_static	: Vec2::new(0.0, 0.0),
+		blue_static		: Vec2::new(-0.5, 0.5),
+		red_dynamic		: Vec2::new(3.0, 1.0),
+		green_dynamic	: Vec2::new(0.0, -1.0),
+		blue_dynamic	: Vec2::new(-2.5, 1.5),
+	};
+
+	pub const MILD : Self = Self {
+		red_static		: Vec2::new(0.3, 0.0),
+		green_static	: Vec2::new(0.0, 0.0),
+		blue_static		: Vec2::new(-0.2, 0.0),
+		red_dynamic		: Vec2::new(1.0, 0.3),
+		green_dynamic	: Vec2::new(0.0, 0.0),
+		blue_dynamic	: Vec2::new(-0.8, 0.5),
+	};
+
+	pub fn new(preset: CrtConvergencePreset) -> Self {
+		match preset {
+			CrtConvergencePreset::Mild		=> CrtConvergenceSettings::MILD,
+			CrtConvergencePreset::Strong	=> CrtConvergenceSettings::STRONG,
+		}
+	}
+
+	pub fn with_scale(mut self, scale: f32) -> Self {
+		self = self * scale;
+		self
+	}
+
+	pub fn set_preset_scaled(&mut self, preset: CrtConvergencePreset, scale: f32) {
+		*self = CrtConvergenceSettings::new(preset).with_scale(scale);
+	}
+}
+
+impl ops::Mul<f32> for CrtConvergenceSettings {
+	type Output = CrtConvergenceSettings;
+
+	fn mul(self, rhs: f32) -> Self::Output {
+		let scale = rhs.max(MIN_SCALE);
+
+		Self::Output {
+			red_static		: self.red_static		* scale,
+			green_static	: self.green_static		* scale,
+			blue_static		: self.blue_static		* scale,
+			red_dynamic		: self.red_dynamic		* scale,
+			green_dynamic	: self.green_dynamic	* scale,
+			blue_dynamic	: self.blue_dynamic		* scale,
+		}
+    }
+}
+
+impl Default for CrtConvergenceSettings {
+	fn default() -> Self {
+        CrtConvergenceSettings::MILD
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrtConvergencePreset {
+	Mild,
+	Strong
+}
+
+impl CrtEffect for CrtConvergenceSettings {
+	const NAME : &'static str = "convergence";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![CONVERGENCE_PASS0_SHADER_HANDLE.into()]
+	}
+
+	fn pass_enabled(&self, _pass: usize) -> bool {
+		[
+			self.red_static,
+			self.green_static,
+			self.blue_static,
+			self.red_dynamic,
+			self.green_dynamic,
+			self.blue_dynamic,
+		]
+		.iter()
+		.any(|offset| offset.length() > MIN_AMOUNT)
+	}
+}
@@ -0,0 +1,10 @@
+#define_import_path bevy_crt_galore::convergence
+
+struct CrtSettings {
+	red_static			: vec2<f32>,	// Red offset over the whole screen in texels
+	green_static		: vec2<f32>,	// Green offset over the whole screen in texels
+	blue_static			: vec2<f32>,	// Blue offset over the whole screen in texels
+	red_dynamic			: vec2<f32>,	// Additional red offset in the corners in texels, zero in the center
+	green_dynamic		: vec2<f32>,	// Additional green offset in the corners in texels, zero in the center
+	blue_dynamic		: vec2<f32>,	// Additional blue offset in the corners in texels, zero in the center
+}
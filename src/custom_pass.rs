@@ -0,0 +1,56 @@
+use std::marker::PhantomData;
+
+use bevy::{
+	prelude::*,
+	render::{render_resource::ShaderRef, RenderApp},
+};
+
+use super::*;
+
+/// Position of a custom pass relative to the built-in passes of a family, by built-in pass index.
+/// E.g. `CrtPassSlot::After(1)` runs between Endesga's mask (pass1) and distortion (pass2).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrtPassSlot {
+	Before(usize),
+	After(usize),
+}
+
+/// Custom passes of the family with settings `T`, in registration order.
+#[derive(Resource)]
+pub(crate) struct CrtCustomPasses<T> {
+	pub passes	: Vec<(CrtPassSlot, ShaderRef)>,
+	marker		: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for CrtCustomPasses<T> {
+	fn default() -> Self {
+		Self {
+			passes	: Vec::new(),
+			marker	: PhantomData,
+		}
+	}
+}
+
+pub trait CrtAppExt {
+	/// Runs a user fragment shader inside the pass sequence of the family with settings `T`.
+	/// The shader gets the same bindings as the built-in passes, see [`CrtEffect`]. Custom passes are always enabled.
+	/// Has to be called before the app is run, pipelines are created when plugins are finished.
+	fn add_crt_pass<T: CrtEffect>(&mut self, slot: CrtPassSlot, shader: impl Into<ShaderRef>) -> &mut Self;
+}
+
+impl CrtAppExt for App {
+	fn add_crt_pass<T: CrtEffect>(&mut self, slot: CrtPassSlot, shader: impl Into<ShaderRef>) -> &mut Self {
+		let Ok(render_app) = self.get_sub_app_mut(RenderApp) else {
+			warn!("bevy_crt_galore: render app not found, custom {} pass is ignored. add_crt_pass has to be called after the render plugins (DefaultPlugins) are added", T::NAME);
+			return self;
+		};
+
+		render_app
+			.world
+			.get_resource_or_insert_with(CrtCustomPasses::<T>::default)
+			.passes
+			.push((slot, shader.into()));
+
+		self
+	}
+}
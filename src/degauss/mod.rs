@@ -0,0 +1,164 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_resource::{ShaderRef, ShaderType},
+	},
+};
+
+use std::ops;
+
+use super::*;
+
+// $ uuidgen
+pub const DEGAUSS_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0xd48bff85fa184711a78fb9584436c9ffu128);
+pub const DEGAUSS_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0x1d2f6b7a74264f0a81055c9572bf23afu128);
+
+pub struct DegaussCrtPlugin;
+
+impl Plugin for DegaussCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, DEGAUSS_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, DEGAUSS_PASS0_SHADER_HANDLE, "../../assets/shaders/degauss/pass0.wgsl", Shader::from_wgsl);
+
+		app
+			.add_event::<CrtDegaussEvent>()
+			.add_plugins(CrtEffectPlugin::<CrtDegaussSettings>::default())
+			.add_systems(Update, update_crt_degauss);
+	}
+}
+
+/// Degausses the camera, it has to have [`CrtDegaussSettings`]
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CrtDegaussEvent(pub Entity);
+
+/// Degauss burst of the camera, idle until [`CrtDegaussEvent`] or [`CrtDegaussSettings::trigger`].
+/// The shader animates it from `globals.time` so nothing is updated while it plays.
+// IMPORTANT! keep this in sync with src/degauss/settings.wgsl
+#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct CrtDegaussSettings {
+	pub duration	: f32,		// Seconds until the picture settles
+	pub strength	: f32,		// Wobble and swirl of the picture
+	pub purity		: f32,		// Color purity distortion
+	pub started_at	: f32,		// globals.time of the last trigger, negative when idle
+	pub wrap_period	: f32,		// Wrap period of globals.time at the last trigger
+}
+
+impl CrtDegaussSettings {
+	pub const IDLE : f32 = -1.0;
+
+	pub const STRONG : Self = Self {
+		duration	: 2.0,
+		strength	: 2.0,
+		purity		: 1.5,
+		started_at	: Self::IDLE,
+		wrap_period	: 3600.0,
+	};
+
+	pub const MILD : Self = Self {
+		duration	: 1.0,
+		strength	: 1.0,
+		purity		: 1.0,
+		started_at	: Self::IDLE,
+		wrap_period	: 3600.0,
+	};
+
+	pub fn new(preset: CrtDegaussPreset) -> Self {
+		match preset {
+			CrtDegaussPreset::Mild		=> CrtDegaussSettings::MILD,
+			CrtDegaussPreset::Strong	=> CrtDegaussSettings::STRONG,
+		}
+	}
+
+	pub fn with_scale(mut self, scale: f32) -> Self {
+		self = self * scale;
+		self
+	}
+
+	// a burst in progress keeps playing
+	pub fn set_preset_scaled(&mut self, preset: CrtDegaussPreset, scale: f32) {
+		*self = CrtDegaussSettings {
+			started_at	: self.started_at,
+			wrap_period	: self.wrap_period,
+			..CrtDegaussSettings::new(preset).with_scale(scale)
+		};
+	}
+
+	pub fn trigger(&mut self, time: &Time) {
+		self.started_at = time.elapsed_seconds_wrapped();
+		self.wrap_period = time.wrap_period().as_secs_f32();
+	}
+
+	pub fn is_active(&self) -> bool {
+		self.started_at >= 0.0
+	}
+}
+
+impl ops::Mul<f32> for CrtDegaussSettings {
+	type Output = CrtDegaussSettings;
+
+	fn mul(self, rhs: f32) -> Self::Output {
+		let scale = rhs.max(MIN_SCALE);
+
+		Self::Output {
+			duration	: self.duration,
+			strength	: self.strength	* scale,
+			purity		: self.purity	* scale,
+			started_at	: self.started_at,
+			wrap_period	: self.wrap_period,
+		}
+    }
+}
+
+impl Default for CrtDegaussSettings {
+	fn default() -> Self {
+        CrtDegaussSettings::MILD
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrtDegaussPreset {
+	Mild,
+	Strong
+}
+
+fn update_crt_degauss(
+	mut events	: EventReader<CrtDegaussEvent>,
+	mut cameras	: Query<&mut CrtDegaussSettings>,
+	time		: Res<Time>,
+) {
+	for CrtDegaussEvent(camera) in events.read() {
+		match cameras.get_mut(*camera) {
+			Ok(mut settings) => settings.trigger(&time),
+			Err(_) => warn!("bevy_crt_galore: degauss of {:?} without CrtDegaussSettings", camera),
+		}
+	}
+
+	let now = time.elapsed_seconds_wrapped();
+
+	// back to idle once settled so that the pass is skipped
+	for mut settings in &mut cameras {
+		if !settings.is_active() {
+			continue;
+		}
+
+		let elapsed = (now - settings.started_at).rem_euclid(settings.wrap_period);
+
+		if elapsed > settings.duration {
+			settings.started_at = CrtDegaussSettings::IDLE;
+		}
+	}
+}
+
+impl CrtEffect for CrtDegaussSettings {
+	const NAME : &'static str = "degauss";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![DEGAUSS_PASS0_SHADER_HANDLE.into()]
+	}
+
+	fn pass_enabled(&self, _pass: usize) -> bool {
+		self.is_active()
+	}
+}
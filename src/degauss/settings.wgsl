@@ -0,0 +1,9 @@
+#define_import_path bevy_crt_galore::degauss
+
+struct CrtSettings {
+	duration			: f32,		// Seconds until the picture settles
+	strength			: f32,		// Wobble and swirl of the picture
+	purity				: f32,		// Color purity distortion
+	started_at			: f32,		// globals.time of the last trigger, negative when idle
+	wrap_period			: f32,		// Wrap period of globals.time at the last trigger
+}
@@ -0,0 +1,475 @@
+use std::marker::PhantomData;
+
+use bevy::{
+	core_pipeline::fullscreen_vertex_shader::fullscreen_shader_vertex_state,
+	prelude::*,
+	render::{
+		camera::ExtractedCamera,
+		extract_component::{
+			ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin, UniformComponentPlugin,
+		},
+		globals::{GlobalsBuffer, GlobalsUniform},
+		render_graph::NodeRunError,
+		render_resource::{
+			binding_types::{sampler, texture_2d, uniform_buffer},
+			encase::internal::WriteInto,
+			*,
+		},
+		renderer::{RenderContext, RenderDevice},
+		render_asset::RenderAssets,
+		texture::FallbackImage,
+		view::{ViewTarget, ViewUniform, ViewUniformOffset, ViewUniforms},
+		Render, RenderApp, RenderSet,
+	},
+};
+
+use super::*;
+
+/// A CRT family: settings component on the camera plus a sequence of fullscreen fragment passes.
+/// Add [`CrtEffectPlugin`] for it and it gets the same ECS and render graph integration as the built-in families,
+/// including [`CrtChain`] and [`CrtPlacement`].
+///
+/// Every pass has a `fragment` entry point and gets the following bind group:
+/// ```wgsl
+/// @group(0) @binding(0) var screen_texture: texture_2d<f32>;
+/// @group(0) @binding(1) var texture_sampler: sampler;
+/// @group(0) @binding(2) var<uniform> settings: YourSettings;
+/// @group(0) @binding(3) var<uniform> globals: Globals;
+/// @group(0) @binding(4) var<uniform> view: View;
+/// @group(0) @binding(5) var history_texture: texture_2d<f32>;
+/// @group(0) @binding(6) var aux_texture: texture_2d<f32>;
+/// @group(0) @binding(7) var aux_sampler: sampler;
+/// ```
+/// Passes are drawn into the camera viewport, `bevy_crt_galore::common::sample_viewport` samples `screen_texture` (or the history) with `in.uv`.
+pub trait CrtEffect: Component + ExtractComponent<Out = Self> + ShaderType + WriteInto + Clone {
+	/// Used in pipeline, bind group and render pass labels
+	const NAME: &'static str;
+
+	/// Fragment shaders of all passes in execution order. Custom passes from [`CrtAppExt::add_crt_pass`] are not included
+	/// here and don't shift the indices below.
+	fn passes() -> Vec<ShaderRef>;
+
+	/// Disabled passes are skipped
+	fn pass_enabled(&self, _pass: usize) -> bool {
+		true
+	}
+
+	/// Shader defs the pass is specialized with, typically one per active sub-effect so that the rest is compiled out
+	fn shader_defs(&self, _pass: usize) -> Vec<ShaderDefVal> {
+		Vec::new()
+	}
+
+	/// Built-in pass that also writes `@location(1)` into a per view history texture. Every pass of the family can read
+	/// the previous frame of it at binding 5, families without history get a fallback texture there.
+	fn history_pass() -> Option<usize> {
+		None
+	}
+
+	/// Address mode of the sampler at binding 7, tiled images like masks need `Repeat`
+	const AUX_ADDRESS_MODE: AddressMode = AddressMode::ClampToEdge;
+
+	/// Image bound at binding 6 with a sampler at binding 7, looked up in the render world for every view.
+	/// The family is skipped until the image is uploaded, families without one get a fallback texture there.
+	fn aux_image(_world: &World, _view_entity: Entity) -> Option<AssetId<Image>> {
+		None
+	}
+}
+
+/// Shader defs of the sub-effects whose amount is far enough from zero, for [`CrtEffect::shader_defs`]
+pub(crate) fn active_shader_defs(effects: &[(&'static str, f32)]) -> Vec<ShaderDefVal> {
+	effects.iter().filter(|(_, amount)| amount.abs() > MIN_AMOUNT).map(|(def, _)| (*def).into()).collect()
+}
+
+pub struct CrtEffectPlugin<T: CrtEffect>(PhantomData<fn() -> T>);
+
+impl<T: CrtEffect> Default for CrtEffectPlugin<T> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: CrtEffect> Plugin for CrtEffectPlugin<T> {
+	fn build(&self, app: &mut App) {
+		app.add_plugins((
+			ExtractComponentPlugin::<T>::default(),
+			UniformComponentPlugin::<T>::default(),
+		));
+
+		let Ok(render_app) = app.get_sub_app_mut(RenderApp) else { return; };
+
+		render_app
+			.init_resource::<SpecializedRenderPipelines<CrtEffectPipeline<T>>>()
+			.init_resource::<CrtBindGroupCache<T>>()
+			.init_resource::<CrtHistoryTextures<T>>()
+			.add_systems(
+				Render,
+				(
+					prepare_crt_pipelines::<T>,
+					prune_crt_bind_groups::<T>,
+				).in_set(RenderSet::Prepare),
+			);
+
+		if T::history_pass().is_some() {
+			render_app.add_systems(Render, prepare_crt_history_textures::<T>.in_set(RenderSet::PrepareResources));
+		}
+
+		register_crt_family::<T>(render_app, run_crt_effect::<T>);
+	}
+
+	fn finish(&self, app: &mut App) {
+		let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+			return;
+		};
+
+		render_app.init_resource::<CrtEffectPipeline<T>>();
+	}
+}
+
+fn run_crt_effect<'w, T: CrtEffect>(
+	world			: &'w World,
+	view_entity		: Entity,
+	render_context	: &mut RenderContext<'w>,
+) -> Result<(), NodeRunError> {
+	let (Some(view_target), Some(settings_index), Some(view_pipelines)) = (
+		world.get::<ViewTarget>(view_entity),
+		world.get::<DynamicUniformIndex<T>>(view_entity),
+		world.get::<ViewCrtPipelines<T>>(view_entity),
+	) else { return Ok(()) };
+
+	let Some(view_uniform_offset) = world.get::<ViewUniformOffset>(view_entity) else { return Ok(()) };
+
+	let viewport = world.get::<ExtractedCamera>(view_entity).and_then(|camera| camera.viewport.as_ref());
+
+	let crt_pipeline = world.resource::<CrtEffectPipeline<T>>();
+
+	let history = world.resource::<CrtHistoryTextures<T>>().get(view_entity);
+
+	// the history pass can't run without a texture to write to
+	if crt_pipeline.history_pass.is_some() && history.is_none() {
+		return Ok(());
+	}
+
+	let fallback_image = world.resource::<FallbackImage>();
+
+	let history_source = history.map_or(&fallback_image.d2.texture_view, |(source, _)| source);
+
+	let aux_texture = match T::aux_image(world, view_entity) {
+		Some(aux_image) => {
+			let Some(gpu_image) = world.resource::<RenderAssets<Image>>().get(aux_image) else { return Ok(()) };
+			&gpu_image.texture_view
+		},
+		None => &fallback_image.d2.texture_view,
+	};
+
+	let pipeline_cache = world.resource::<PipelineCache>();
+
+	// skip the whole family until every enabled pass is compiled
+	let mut pipelines = Vec::with_capacity(view_pipelines.passes.len());
+	for (pass, pipeline_id) in view_pipelines.passes.iter().enumerate() {
+		let Some(pipeline_id) = pipeline_id else { continue };
+		let Some(pipeline) = pipeline_cache.get_render_pipeline(*pipeline_id) else { return Ok(()) };
+		pipelines.push((pass, pipeline));
+	}
+
+	let settings_uniforms = world.resource::<ComponentUniforms<T>>();
+
+	let Some(settings_binding) = settings_uniforms.uniforms().binding() else { return Ok(()) };
+
+	let globals_buffer = world.resource::<GlobalsBuffer>();
+
+	let Some(global_uniforms) = globals_buffer.buffer.binding() else { return Ok(()) };
+
+	let view_uniforms_buffer = world.resource::<ViewUniforms>();
+
+	let Some(view_uniforms) = view_uniforms_buffer.uniforms.binding() else { return Ok(()) };
+
+	let (Some(settings_buffer), Some(global_uniforms_buffer), Some(view_buffer)) = (
+		settings_uniforms.uniforms().buffer(),
+		globals_buffer.buffer.buffer(),
+		view_uniforms_buffer.uniforms.buffer(),
+	) else { return Ok(()) };
+
+	let bind_group_cache = world.resource::<CrtBindGroupCache<T>>();
+
+	let mut envoke_render_pass = |pass: usize, pipeline: &RenderPipeline| {
+		let post_process = view_target.post_process_write();
+
+		let bind_group_key = CrtBindGroupKey {
+			source		: post_process.source.id(),
+			settings	: settings_buffer.id(),
+			globals		: global_uniforms_buffer.id(),
+			view		: view_buffer.id(),
+			history		: history_source.id(),
+			aux			: aux_texture.id(),
+		};
+
+		let bind_group = bind_group_cache.get_or_create(view_entity, bind_group_key, || {
+			render_context.render_device().create_bind_group(
+				crt_pipeline.bind_group_label.as_str(),
+				&crt_pipeline.layout,
+				// It's important for this to match the BindGroupLayout defined in the PostProcessPipeline
+				&BindGroupEntries::sequential((
+					// Make sure to use the source view
+					post_process.source,
+					// Use the sampler created for the pipeline
+					&crt_pipeline.sampler,
+					// Set the settings binding
+					settings_binding.clone(),
+					// Bevy default global uniforms
+					global_uniforms.clone(),
+					// Bevy view uniforms for the camera viewport
+					view_uniforms.clone(),
+					// Previous frame of the family history
+					history_source,
+					// Family specific image
+					aux_texture,
+					&crt_pipeline.aux_sampler,
+				)),
+			)
+		});
+
+		let mut color_attachments = vec![Some(RenderPassColorAttachment {
+			view: post_process.destination,
+			resolve_target: None,
+			ops: Operations::default(),
+		})];
+
+		if crt_pipeline.history_pass == Some(pass) {
+			if let Some((_, history_destination)) = history {
+				color_attachments.push(Some(RenderPassColorAttachment {
+					view: history_destination,
+					resolve_target: None,
+					ops: Operations::default(),
+				}));
+			}
+		}
+
+		let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+			label: Some(crt_pipeline.pass_labels[pass].as_str()),
+			color_attachments: &color_attachments,
+			depth_stencil_attachment: None,
+			timestamp_writes: None,
+			occlusion_query_set: None,
+		});
+
+		// keep split-screen panes apart, shaders treat in.uv as local to the viewport
+		if let Some(viewport) = viewport {
+			render_pass.set_camera_viewport(viewport);
+		}
+
+		render_pass.set_render_pipeline(pipeline);
+		render_pass.set_bind_group(0, &bind_group, &[settings_index.index(), view_uniform_offset.offset]);
+		render_pass.draw(0..3, 0..1);
+	};
+
+	for (pass, pipeline) in pipelines {
+		envoke_render_pass(pass, pipeline);
+	}
+
+	Ok(())
+}
+
+#[derive(Resource)]
+struct CrtEffectPipeline<T> {
+	layout				: BindGroupLayout,
+	sampler				: Sampler,
+	aux_sampler			: Sampler,
+	shaders				: Vec<Handle<Shader>>,
+	// built-in pass index of every pass, None for custom ones
+	builtin_passes		: Vec<Option<usize>>,
+	// index among all passes, unlike CrtEffect::history_pass
+	history_pass		: Option<usize>,
+	bind_group_label	: String,
+	pass_labels			: Vec<String>,
+	marker				: PhantomData<fn() -> T>,
+}
+
+impl<T: CrtEffect> FromWorld for CrtEffectPipeline<T> {
+	fn from_world(world: &mut World) -> Self {
+		let render_device = world.resource::<RenderDevice>();
+
+		let layout = render_device.create_bind_group_layout(
+			format!("crt_{}_bind_group_layout", T::NAME).as_str(),
+			&BindGroupLayoutEntries::sequential(
+				ShaderStages::FRAGMENT,
+				(
+					// The screen texture
+					texture_2d(TextureSampleType::Float { filterable: true }),
+					// The screen texture sampler
+					sampler(SamplerBindingType::Filtering),
+					// The settings uniform that will control the effect, offset per view
+					uniform_buffer::<T>(true),
+					// Default bevy globals
+					uniform_buffer::<GlobalsUniform>(false),
+					// Default bevy view uniforms, offset per view
+					uniform_buffer::<ViewUniform>(true),
+					// The previous frame of the history texture
+					texture_2d(TextureSampleType::Float { filterable: true }),
+					// The family specific image and its sampler
+					texture_2d(TextureSampleType::Float { filterable: true }),
+					sampler(SamplerBindingType::Filtering),
+				),
+			),
+		);
+
+		// We can create the sampler here since it won't change at runtime and doesn't depend on the view
+		let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+
+		let aux_sampler = render_device.create_sampler(&SamplerDescriptor {
+			address_mode_u	: T::AUX_ADDRESS_MODE,
+			address_mode_v	: T::AUX_ADDRESS_MODE,
+			mag_filter		: FilterMode::Linear,
+			min_filter		: FilterMode::Linear,
+			..default()
+		});
+
+		let asset_server = world.resource::<AssetServer>();
+
+		// ShaderRef isn't Clone, custom passes are shared between slots so everything is resolved to handles up front
+		let load_shader = |shader: &ShaderRef| match shader {
+			ShaderRef::Handle(handle) => handle.clone(),
+			ShaderRef::Path(path) => asset_server.load(path.clone()),
+			ShaderRef::Default => panic!("bevy_crt_galore: {} has a pass without a shader", T::NAME),
+		};
+
+		let custom_passes : Vec<(CrtPassSlot, Handle<Shader>)> = world
+			.get_resource::<CrtCustomPasses<T>>()
+			.map(|custom| custom.passes.iter().map(|(slot, shader)| (*slot, load_shader(shader))).collect())
+			.unwrap_or_default();
+
+		let custom_passes_at = |slot: CrtPassSlot| {
+			custom_passes.iter().filter(move |(custom_slot, _)| *custom_slot == slot).map(|(_, shader)| (None, shader.clone()))
+		};
+
+		let builtin_count = T::passes().len();
+
+		let mut passes : Vec<(Option<usize>, Handle<Shader>)> = Vec::new();
+
+		for (builtin, shader) in T::passes().iter().enumerate() {
+			passes.extend(custom_passes_at(CrtPassSlot::Before(builtin)));
+			passes.push((Some(builtin), load_shader(shader)));
+			passes.extend(custom_passes_at(CrtPassSlot::After(builtin)));
+		}
+
+		// slots past the last built-in pass go to the end
+		passes.extend(
+			custom_passes
+				.iter()
+				.filter(|(slot, _)| matches!(slot, CrtPassSlot::Before(i) | CrtPassSlot::After(i) if *i >= builtin_count))
+				.map(|(_, shader)| (None, shader.clone()))
+		);
+
+		let mut custom_count = 0;
+
+		let pass_labels = passes
+			.iter()
+			.map(|(builtin, _)| match builtin {
+				Some(builtin) => format!("crt_{}_pass{}", T::NAME, builtin),
+				None => {
+					custom_count += 1;
+					format!("crt_{}_custom_pass{}", T::NAME, custom_count - 1)
+				},
+			})
+			.collect();
+
+		let (builtin_passes, shaders) : (Vec<Option<usize>>, Vec<Handle<Shader>>) = passes.into_iter().unzip();
+
+		let history_pass = T::history_pass().and_then(|history_pass| {
+			builtin_passes.iter().position(|builtin| *builtin == Some(history_pass))
+		});
+
+		Self {
+			layout,
+			sampler,
+			aux_sampler,
+			shaders,
+			builtin_passes,
+			history_pass,
+			bind_group_label: format!("crt_{}_bind_group", T::NAME),
+			pass_labels,
+			marker: PhantomData,
+		}
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct CrtEffectPipelineKey {
+	pass			: usize,
+	texture_format	: TextureFormat,
+	shader_defs		: Vec<ShaderDefVal>,
+}
+
+impl<T: CrtEffect> SpecializedRenderPipeline for CrtEffectPipeline<T> {
+	type Key = CrtEffectPipelineKey;
+
+	fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+		let mut targets = vec![Some(ColorTargetState {
+			format: key.texture_format,
+			blend: None,
+			write_mask: ColorWrites::ALL,
+		})];
+
+		if self.history_pass == Some(key.pass) {
+			targets.push(Some(ColorTargetState {
+				format: CRT_HISTORY_FORMAT,
+				blend: None,
+				write_mask: ColorWrites::ALL,
+			}));
+		}
+
+		RenderPipelineDescriptor {
+			label: Some(format!("{}_pipeline", self.pass_labels[key.pass]).into()),
+			layout: vec![self.layout.clone()],
+			vertex: fullscreen_shader_vertex_state(),
+			fragment: Some(FragmentState {
+				shader: self.shaders[key.pass].clone(),
+				shader_defs: key.shader_defs,
+				entry_point: "fragment".into(),
+				targets,
+			}),
+			primitive: PrimitiveState::default(),
+			depth_stencil: None,
+			multisample: MultisampleState::default(),
+			push_constant_ranges: vec![],
+		}
+	}
+}
+
+/// Pipeline of every pass for the view, `None` for disabled ones
+#[derive(Component)]
+struct ViewCrtPipelines<T> {
+	passes	: Vec<Option<CachedRenderPipelineId>>,
+	marker	: PhantomData<fn() -> T>,
+}
+
+fn prepare_crt_pipelines<T: CrtEffect>(
+	mut commands	: Commands,
+	pipeline_cache	: Res<PipelineCache>,
+	mut pipelines	: ResMut<SpecializedRenderPipelines<CrtEffectPipeline<T>>>,
+	crt_pipeline	: Res<CrtEffectPipeline<T>>,
+	views			: Query<(Entity, &ViewTarget, &T)>,
+) {
+	for (entity, view_target, settings) in &views {
+		// post processing happens in the main texture, not in the render target (window or image) itself
+		let texture_format = view_target.main_texture_format();
+
+		let passes = crt_pipeline.builtin_passes
+			.iter()
+			.enumerate()
+			.map(|(pass, builtin)| {
+				let enabled = match builtin {
+					Some(builtin) => settings.pass_enabled(*builtin),
+					None => true,
+				};
+
+				enabled.then(|| {
+					let shader_defs = builtin.map(|builtin| settings.shader_defs(builtin)).unwrap_or_default();
+
+					pipelines.specialize(&pipeline_cache, &crt_pipeline, CrtEffectPipelineKey { pass, texture_format, shader_defs })
+				})
+			})
+			.collect();
+
+		commands.entity(entity).insert(ViewCrtPipelines::<T> { passes, marker: PhantomData });
+	}
+}
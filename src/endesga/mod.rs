@@ -1,29 +1,11 @@
 use std::ops;
 
 use bevy::{
-    asset::load_internal_asset,
-	core_pipeline::{
-		core_3d::graph::{Core3d, Node3d},
-		fullscreen_vertex_shader::fullscreen_shader_vertex_state,
-	},
-	ecs::query::QueryItem,
+	asset::load_internal_asset,
 	prelude::*,
 	render::{
-		extract_component::{
-			ComponentUniforms, ExtractComponent, ExtractComponentPlugin, UniformComponentPlugin,
-		},
-		render_graph::{
-			NodeRunError, RenderGraphApp, RenderGraphContext, RenderLabel, ViewNode, ViewNodeRunner,
-		},
-		render_resource::{
-			binding_types::{sampler, texture_2d, uniform_buffer},
-			*,
-		},
-		renderer::{RenderContext, RenderDevice},
-		globals::{GlobalsBuffer, GlobalsUniform},
-		texture::BevyDefault,
-		view::ViewTarget,
-		RenderApp,
+		extract_component::ExtractComponent,
+		render_resource::{AddressMode, ShaderDefVal, ShaderRef, ShaderType},
 	},
 };
 
@@ -44,34 +26,7 @@ impl Plugin for EndesgaCrtPlugin {
 		load_internal_asset!(app, ENDESGA_PASS1_SHADER_HANDLE, "../../assets/shaders/endesga/pass1.wgsl", Shader::from_wgsl);
 		load_internal_asset!(app, ENDESGA_PASS2_SHADER_HANDLE, "../../assets/shaders/endesga/pass2.wgsl", Shader::from_wgsl);
 
-		app.add_plugins((
-			ExtractComponentPlugin::<CrtEndesgaSettings>::default(),
-			UniformComponentPlugin::<CrtEndesgaSettings>::default(),
-		));
-
-		let Ok(render_app) = app.get_sub_app_mut(RenderApp) else { return; };
-
-		render_app
-			.add_render_graph_node::<ViewNodeRunner<CrtEndesgaNode>>(
-				Core3d,
-				CrtEndesgaLabel,
-			)
-			.add_render_graph_edges(
-				Core3d,
-				(
-					Node3d::EndMainPass,
-					CrtEndesgaLabel,
-					Node3d::Bloom,
-				),
-			);
-	}
-
-	fn finish(&self, app: &mut App) {
-		let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
-			return;
-		};
-
-		render_app.init_resource::<CrtEndesgaPipeline>();
+		app.add_plugins(CrtEffectPlugin::<CrtEndesgaSettings>::default());
 	}
 }
 
@@ -84,6 +39,9 @@ pub struct CrtEndesgaSettings {
 	pub rounded_amount		: f32,
 	pub pixelate_amount		: f32,
 	pub mask_amount			: f32,
+	pub mask_type			: u32,		// Sub-pixel layout, see CrtMaskType
+	pub mask_size			: f32,		// Triad pitch in pixels
+	pub mask_border			: f32,		// Gap darkness (0 to 1), not used by the hex mask
 	pub distortion_amount	: f32,
 	pub glow_amount			: f32,
 }
@@ -96,6 +54,9 @@ impl CrtEndesgaSettings {
 		rounded_amount		: 0.07,
 		pixelate_amount		: 0.7,
 		mask_amount			: 0.7,
+		mask_type			: CrtMaskType::Hex as u32,
+		mask_size			: 7.0,
+		mask_border			: 0.5,
 		distortion_amount	: 0.07,
 		glow_amount			: 3.0,
 	};
@@ -107,6 +68,9 @@ impl CrtEndesgaSettings {
 		rounded_amount		: 0.03,
 		pixelate_amount		: 0.01,
 		mask_amount			: 0.01,
+		mask_type			: CrtMaskType::Hex as u32,
+		mask_size			: 7.0,
+		mask_border			: 0.5,
 		distortion_amount	: 0.017,
 		glow_amount			: 1.9,
 	};
@@ -129,6 +93,11 @@ impl CrtEndesgaSettings {
 		self
 	}
 
+	pub fn with_mask_type(mut self, mask_type: CrtMaskType) -> Self {
+		self.mask_type = mask_type.into();
+		self
+	}
+
 	pub fn set_preset_scaled(&mut self, preset: CrtEndesgaPreset, scale: f32) {
 		*self = CrtEndesgaSettings::new(preset).with_scale(scale);
 	}
@@ -149,6 +118,9 @@ impl ops::Mul<f32> for CrtEndesgaSettings {
 			rounded_amount		: self.rounded_amount		* scale,
 			pixelate_amount		: self.pixelate_amount		* scale,
 			mask_amount			: self.mask_amount			* scale,
+			mask_type			: self.mask_type,
+			mask_size			: self.mask_size,
+			mask_border			: self.mask_border,
 			distortion_amount	: self.distortion_amount	* scale,
 			glow_amount,
 		}
@@ -166,163 +138,53 @@ pub enum CrtEndesgaPreset {
 	Mild,
 	Strong
 }
-#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
-struct CrtEndesgaLabel;
-
-#[derive(Default)]
-struct CrtEndesgaNode;
-
-impl ViewNode for CrtEndesgaNode {
-	type ViewQuery = (
-		&'static ViewTarget,
-		&'static CrtEndesgaSettings,
-	);
-
-	fn run(
-		&self,
-		_graph: &mut RenderGraphContext,
-		render_context: &mut RenderContext,
-		(view_target, settings): QueryItem<Self::ViewQuery>,
-		world: &World,
-	) -> Result<(), NodeRunError> {
-		let crt_pipeline = world.resource::<CrtEndesgaPipeline>();
-
-		let pipeline_cache = world.resource::<PipelineCache>();
-
-		let Some(pass0_pipeline) = pipeline_cache.get_render_pipeline(crt_pipeline.pass0_pipeline_id) else { return Ok(()) };
-		let Some(pass1_pipeline) = pipeline_cache.get_render_pipeline(crt_pipeline.pass1_pipeline_id) else { return Ok(()) };
-		let Some(pass2_pipeline) = pipeline_cache.get_render_pipeline(crt_pipeline.pass2_pipeline_id) else { return Ok(()) };
-
-		let settings_uniforms = world.resource::<ComponentUniforms<CrtEndesgaSettings>>();
 
-		let Some(settings_binding) = settings_uniforms.uniforms().binding() else { return Ok(()) };
+impl CrtEffect for CrtEndesgaSettings {
+	const NAME : &'static str = "endesga";
 
-		let globals_buffer = world.resource::<GlobalsBuffer>();
+	// mask tiles repeat across the screen
+	const AUX_ADDRESS_MODE : AddressMode = AddressMode::Repeat;
 
-		let Some(global_uniforms) = globals_buffer.buffer.binding() else { return Ok(()) };
-
-		let mut envoke_render_pass = |pipeline: &RenderPipeline, name: &str| {
-			let post_process = view_target.post_process_write();
-
-			let bind_group = render_context.render_device().create_bind_group(
-				"crt_endesga_bind_group",
-				&crt_pipeline.layout,
-				// It's important for this to match the BindGroupLayout defined in the PostProcessPipeline
-				&BindGroupEntries::sequential((
-					// Make sure to use the source view
-					post_process.source,
-					// Use the sampler created for the pipeline
-					&crt_pipeline.sampler,
-					// Set the settings binding
-					settings_binding.clone(),
-					// Bevy default global uniforms
-					global_uniforms.clone(),
-				)),
-			);
+	fn passes() -> Vec<ShaderRef> {
+		vec![
+			ENDESGA_PASS0_SHADER_HANDLE.into(),
+			ENDESGA_PASS1_SHADER_HANDLE.into(),
+			ENDESGA_PASS2_SHADER_HANDLE.into(),
+		]
+	}
 
-			let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
-				label: Some(name),
-				color_attachments: &[Some(RenderPassColorAttachment {
-					view: post_process.destination,
-					resolve_target: None,
-					ops: Operations::default(),
-				})],
-				depth_stencil_attachment: None,
-				timestamp_writes: None,
-				occlusion_query_set: None,
-			});
+	fn pass_enabled(&self, pass: usize) -> bool {
+		// pixelation and mask go together
+		pass != 1 || (self.pixelate_amount > MIN_AMOUNT && self.mask_amount > MIN_AMOUNT)
+	}
 
-			render_pass.set_render_pipeline(pipeline);
-			render_pass.set_bind_group(0, &bind_group, &[]);
-			render_pass.draw(0..3, 0..1);
+	fn shader_defs(&self, pass: usize) -> Vec<ShaderDefVal> {
+		let effects = match pass {
+			0 => vec![
+				("ABERRATION",	self.aberration_amount),
+				("NOISE",		self.noise_amount),
+				("VIGNETTE",	self.vignette_amount),
+				("ROUNDED",		self.rounded_amount),
+			],
+			1 => vec![
+				("PIXELATE",	self.pixelate_amount),
+				("MASK",		self.mask_amount),
+			],
+			_ => vec![
+				("DISTORTION",	self.distortion_amount),
+				("GLOW",		self.glow_amount - 1.0),
+			],
 		};
 
-		envoke_render_pass(pass0_pipeline, "crt_endesga_pass0");
-		if settings.pixelate_amount > MIN_AMOUNT && settings.mask_amount > MIN_AMOUNT {
-			envoke_render_pass(pass1_pipeline, "crt_endesga_pass1");
-		}
-		envoke_render_pass(pass2_pipeline, "crt_endesga_pass2");
-
-		Ok(())
+		active_shader_defs(&effects)
 	}
-}
 
-#[derive(Resource)]
-struct CrtEndesgaPipeline {
-	layout				: BindGroupLayout,
-	sampler				: Sampler,
-	pass0_pipeline_id	: CachedRenderPipelineId,
-	pass1_pipeline_id	: CachedRenderPipelineId,
-	pass2_pipeline_id	: CachedRenderPipelineId,
-}
-
-impl FromWorld for CrtEndesgaPipeline {
-	fn from_world(world: &mut World) -> Self {
-		let render_device = world.resource::<RenderDevice>();
-
-		let layout = render_device.create_bind_group_layout(
-			"crt_galore_bind_group_layout",
-			&BindGroupLayoutEntries::sequential(
-				ShaderStages::FRAGMENT,
-				(
-					// The screen texture
-					texture_2d(TextureSampleType::Float { filterable: true }),
-					// The screen texture sampler
-					sampler(SamplerBindingType::Filtering),
-					// The settings uniform that will control the effect
-					uniform_buffer::<CrtEndesgaSettings>(false),
-					// Default bevy globals
-					uniform_buffer::<GlobalsUniform>(false)
-				),
-			),
-		);
-
-		// We can create the sampler here since it won't change at runtime and doesn't depend on the view
-		let sampler = render_device.create_sampler(&SamplerDescriptor::default());
-
-		let shader0 = ENDESGA_PASS0_SHADER_HANDLE.clone();
-		let shader1 = ENDESGA_PASS1_SHADER_HANDLE.clone();
-		let shader2 = ENDESGA_PASS2_SHADER_HANDLE.clone();
-
-		fn make_pipeline(
-			world			: &mut World,
-			layout			: &BindGroupLayout,
-			shader			: Handle<Shader>,
-			pipeline_label	: &'static str,
-		) -> CachedRenderPipelineId {
-			world
-				.resource_mut::<PipelineCache>()
-				.queue_render_pipeline(RenderPipelineDescriptor {
-					label: Some(pipeline_label.into()),
-					layout: vec![layout.clone()],
-					vertex: fullscreen_shader_vertex_state(),
-					fragment: Some(FragmentState {
-						shader,
-						shader_defs: vec![],
-						entry_point: "fragment".into(),
-						targets: vec![Some(ColorTargetState {
-							format: TextureFormat::Rgba16Float, // bevy_default(),
-							blend: None,
-							write_mask: ColorWrites::ALL,
-						})],
-					}),
-					primitive: PrimitiveState::default(),
-					depth_stencil: None,
-					multisample: MultisampleState::default(),
-					push_constant_ranges: vec![],
-				})
+	fn aux_image(world: &World, view_entity: Entity) -> Option<AssetId<Image>> {
+		// procedural masks never sample the texture so there's no need to wait for it
+		if world.get::<CrtEndesgaSettings>(view_entity)?.mask_type != CrtMaskType::Texture as u32 {
+			return None;
 		}
 
-		let pipeline0_id = make_pipeline(world, &layout, shader0, "crt_endesga_pass0_pipeline");
-		let pipeline1_id = make_pipeline(world, &layout, shader1, "crt_endesga_pass1_pipeline");
-		let pipeline2_id = make_pipeline(world, &layout, shader2, "crt_endesga_pass2_pipeline");
-
-		Self {
-			layout,
-			sampler,
-			pass0_pipeline_id: pipeline0_id,
-			pass1_pipeline_id: pipeline1_id,
-			pass2_pipeline_id: pipeline2_id,
-		}
+		world.get::<CrtMaskTexture>(view_entity).map(|mask| mask.0.id())
 	}
 }
@@ -7,6 +7,9 @@ struct CrtSettings {
 	rounded_amount		: f32,
 	pixelate_amount		: f32,
 	mask_amount			: f32,
+	mask_type			: u32,		// Sub-pixel layout, see CrtMaskType
+	mask_size			: f32,		// Triad pitch in pixels
+	mask_border			: f32,		// Gap darkness (0 to 1), not used by the hex mask
 	distortion_amount	: f32,
 	glow_amount			: f32,
 }
\ No newline at end of file
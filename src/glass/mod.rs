@@ -0,0 +1,141 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::{ExtractComponent, ExtractComponentPlugin},
+		render_resource::{ShaderDefVal, ShaderRef, ShaderType},
+	},
+};
+
+use std::ops;
+
+use super::*;
+
+// $ uuidgen
+pub const GLASS_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0x2ea895fd5fa0497d82d4dc258ba78757u128);
+pub const GLASS_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0x9643e733afdb41d89630222c44d3b6a7u128);
+
+pub struct GlassCrtPlugin;
+
+impl Plugin for GlassCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, GLASS_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, GLASS_PASS0_SHADER_HANDLE, "../../assets/shaders/glass/pass0.wgsl", Shader::from_wgsl);
+
+		app.add_plugins((
+			ExtractComponentPlugin::<CrtGlassReflection>::default(),
+			CrtEffectPlugin::<CrtGlassSettings>::default(),
+		));
+	}
+}
+
+/// Front glass of the tube: glare hotspot, room reflection and refraction. Follows the screen geometry when it runs
+/// after the curvature of Endesga `pass2` or Xor, which is the default order; keep it that way in a [`CrtChain`].
+// IMPORTANT! keep this in sync with src/glass/settings.wgsl
+#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct CrtGlassSettings {
+	pub reflection_color		: Vec3,		// Tints the reflection texture, or the room color without one
+	pub reflection_intensity	: f32,		// Diffuse room reflection strength
+	pub glare_position			: Vec2,		// Glare hotspot in screen uv
+	pub glare_size				: f32,		// Glare radius in screen heights
+	pub glare_intensity			: f32,		// Specular glare strength
+	pub curvature				: f32,		// Glass bulge, match it to the screen curvature of the families before
+	pub refraction				: f32,		// Picture offset towards the edges caused by the glass thickness, grows with curvature
+}
+
+/// Room reflected in the glass of the camera with [`CrtGlassSettings`], tinted by `reflection_color`
+#[derive(Component, Clone, ExtractComponent)]
+pub struct CrtGlassReflection(pub Handle<Image>);
+
+impl CrtGlassSettings {
+	pub const STRONG : Self = Self {
+		reflection_color		: Vec3::new(0.6, 0.62, 0.7),
+		reflection_intensity	: 0.08,
+		glare_position			: Vec2::new(0.25, 0.2),
+		glare_size				: 0.25,
+		glare_intensity			: 0.15,
+		curvature				: 0.2,
+		refraction				: 0.02,
+	};
+
+	pub const MILD : Self = Self {
+		reflection_color		: Vec3::new(0.6, 0.62, 0.7),
+		reflection_intensity	: 0.03,
+		glare_position			: Vec2::new(0.25, 0.2),
+		glare_size				: 0.2,
+		glare_intensity			: 0.06,
+		curvature				: 0.1,
+		refraction				: 0.015,
+	};
+
+	pub fn new(preset: CrtGlassPreset) -> Self {
+		match preset {
+			CrtGlassPreset::Mild	=> CrtGlassSettings::MILD,
+			CrtGlassPreset::Strong	=> CrtGlassSettings::STRONG,
+		}
+	}
+
+	pub fn with_scale(mut self, scale: f32) -> Self {
+		self = self * scale;
+		self
+	}
+
+	pub fn set_preset_scaled(&mut self, preset: CrtGlassPreset, scale: f32) {
+		*self = CrtGlassSettings::new(preset).with_scale(scale);
+	}
+}
+
+impl ops::Mul<f32> for CrtGlassSettings {
+	type Output = CrtGlassSettings;
+
+	fn mul(self, rhs: f32) -> Self::Output {
+		let scale = rhs.max(MIN_SCALE);
+
+		Self::Output {
+			reflection_intensity	: self.reflection_intensity	* scale,
+			glare_intensity			: self.glare_intensity		* scale,
+			curvature				: self.curvature			* scale,
+			refraction				: self.refraction			* scale,
+			..self
+		}
+    }
+}
+
+impl Default for CrtGlassSettings {
+	fn default() -> Self {
+        CrtGlassSettings::MILD
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrtGlassPreset {
+	Mild,
+	Strong
+}
+
+impl CrtEffect for CrtGlassSettings {
+	const NAME : &'static str = "glass";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![GLASS_PASS0_SHADER_HANDLE.into()]
+	}
+
+	fn pass_enabled(&self, pass: usize) -> bool {
+		!self.shader_defs(pass).is_empty()
+	}
+
+	fn shader_defs(&self, _pass: usize) -> Vec<ShaderDefVal> {
+		let effects = [
+			("REFLECTION",	self.reflection_intensity),
+			("GLARE",		self.glare_intensity),
+			// refraction offset is tiny by nature
+			("REFRACTION",	self.refraction * 1000.0),
+		];
+
+		active_shader_defs(&effects)
+	}
+
+	fn aux_image(world: &World, view_entity: Entity) -> Option<AssetId<Image>> {
+		world.get::<CrtGlassReflection>(view_entity).map(|reflection| reflection.0.id())
+	}
+}
@@ -0,0 +1,11 @@
+#define_import_path bevy_crt_galore::glass
+
+struct CrtSettings {
+	reflection_color		: vec3<f32>,	// Tints the reflection texture, or the room color without one
+	reflection_intensity	: f32,			// Diffuse room reflection strength
+	glare_position			: vec2<f32>,	// Glare hotspot in screen uv
+	glare_size				: f32,			// Glare radius in screen heights
+	glare_intensity			: f32,			// Specular glare strength
+	curvature				: f32,			// Glass bulge, match it to the screen curvature of the families before
+	refraction				: f32,			// Picture offset towards the edges caused by the glass thickness, grows with curvature
+}
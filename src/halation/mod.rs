@@ -0,0 +1,105 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_resource::{ShaderRef, ShaderType},
+	},
+};
+
+use std::ops;
+
+use super::*;
+
+// $ uuidgen
+pub const HALATION_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0xa0e8bad48c1148209b8fdf6fca424c7cu128);
+pub const HALATION_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0x338031ae2d2f4973aae7bf7c584add2eu128);
+
+pub struct HalationCrtPlugin;
+
+impl Plugin for HalationCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, HALATION_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, HALATION_PASS0_SHADER_HANDLE, "../../assets/shaders/halation/pass0.wgsl", Shader::from_wgsl);
+
+		app.add_plugins(CrtEffectPlugin::<CrtHalationSettings>::default());
+	}
+}
+
+/// Light diffusing inside the tube glass around bright areas. Unlike `glow_amount` of the other families it blurs
+/// the picture itself, so it glows without `BloomSettings` on the camera and in ldr.
+// IMPORTANT! keep this in sync with src/halation/settings.wgsl
+#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct CrtHalationSettings {
+	pub radius		: f32,		// How far light diffuses in the glass in screen heights
+	pub strength	: f32,		// How much of the diffused light is added back
+	pub threshold	: f32,		// Only light above this diffuses (0 = everything)
+}
+
+impl CrtHalationSettings {
+	pub const STRONG : Self = Self {
+		radius		: 0.04,
+		strength	: 0.8,
+		threshold	: 0.3,
+	};
+
+	pub const MILD : Self = Self {
+		radius		: 0.02,
+		strength	: 0.3,
+		threshold	: 0.5,
+	};
+
+	pub fn new(preset: CrtHalationPreset) -> Self {
+		match preset {
+			CrtHalationPreset::Mild		=> CrtHalationSettings::MILD,
+			CrtHalationPreset::Strong	=> CrtHalationSettings::STRONG,
+		}
+	}
+
+	pub fn with_scale(mut self, scale: f32) -> Self {
+		self = self * scale;
+		self
+	}
+
+	pub fn set_preset_scaled(&mut self, preset: CrtHalationPreset, scale: f32) {
+		*self = CrtHalationSettings::new(preset).with_scale(scale);
+	}
+}
+
+impl ops::Mul<f32> for CrtHalationSettings {
+	type Output = CrtHalationSettings;
+
+	fn mul(self, rhs: f32) -> Self::Output {
+		let scale = rhs.max(MIN_SCALE);
+
+		Self::Output {
+			radius		: self.radius	* scale,
+			strength	: self.strength	* scale,
+			threshold	: self.threshold,
+		}
+    }
+}
+
+impl Default for CrtHalationSettings {
+	fn default() -> Self {
+        CrtHalationSettings::MILD
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrtHalationPreset {
+	Mild,
+	Strong
+}
+
+impl CrtEffect for CrtHalationSettings {
+	const NAME : &'static str = "halation";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![HALATION_PASS0_SHADER_HANDLE.into()]
+	}
+
+	fn pass_enabled(&self, _pass: usize) -> bool {
+		self.strength > MIN_AMOUNT && self.radius > 0.0
+	}
+}
@@ -0,0 +1,7 @@
+#define_import_path bevy_crt_galore::halation
+
+struct CrtSettings {
+	radius				: f32,		// How far light diffuses in the glass in screen heights
+	strength			: f32,		// How much of the diffused light is added back
+	threshold			: f32,		// Only light above this diffuses (0 = everything)
+}
@@ -0,0 +1,94 @@
+use std::marker::PhantomData;
+
+use bevy::{
+	prelude::*,
+	render::{
+		camera::ExtractedCamera,
+		render_resource::{Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages, TextureView},
+		renderer::RenderDevice,
+		texture::CachedTexture,
+	},
+	utils::HashMap,
+};
+
+use super::*;
+
+/// Half float so that slow decay doesn't get stuck on 8 bit steps in ldr
+pub(crate) const CRT_HISTORY_FORMAT : TextureFormat = TextureFormat::Rgba16Float;
+
+struct ViewHistory {
+	size		: UVec2,
+	textures	: [CachedTexture; 2],
+	// index of the texture written this frame, the other one holds the previous frame
+	write		: usize,
+}
+
+/// Per view history textures of the family with settings `T`, see [`CrtEffect::history_pass`].
+/// Owned per view instead of taken from the `TextureCache` so that cameras of the same size never swap histories.
+#[derive(Resource)]
+pub(crate) struct CrtHistoryTextures<T> {
+	views	: HashMap<Entity, ViewHistory>,
+	marker	: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for CrtHistoryTextures<T> {
+	fn default() -> Self {
+		Self {
+			views	: HashMap::default(),
+			marker	: PhantomData,
+		}
+	}
+}
+
+impl<T> CrtHistoryTextures<T> {
+	/// Previous frame to read from and the texture receiving the current one
+	pub(crate) fn get(&self, view_entity: Entity) -> Option<(&TextureView, &TextureView)> {
+		self.views.get(&view_entity).map(|history| (
+			&history.textures[1 - history.write].default_view,
+			&history.textures[history.write].default_view,
+		))
+	}
+}
+
+pub(crate) fn prepare_crt_history_textures<T: CrtEffect>(
+	mut history		: ResMut<CrtHistoryTextures<T>>,
+	render_device	: Res<RenderDevice>,
+	views			: Query<(Entity, &ExtractedCamera), With<T>>,
+) {
+	history.views.retain(|view_entity, _| views.contains(*view_entity));
+
+	for (view_entity, camera) in &views {
+		let Some(size) = camera.physical_target_size else { continue };
+
+		if let Some(view_history) = history.views.get_mut(&view_entity) {
+			if view_history.size == size {
+				view_history.write = 1 - view_history.write;
+				continue;
+			}
+		}
+
+		// new view or resized target, restart from a black history
+		let create_texture = |label: &'static str| {
+			let texture = render_device.create_texture(&TextureDescriptor {
+				label			: Some(label),
+				size			: Extent3d { width: size.x, height: size.y, depth_or_array_layers: 1 },
+				mip_level_count	: 1,
+				sample_count	: 1,
+				dimension		: TextureDimension::D2,
+				format			: CRT_HISTORY_FORMAT,
+				usage			: TextureUsages::TEXTURE_BINDING | TextureUsages::RENDER_ATTACHMENT,
+				view_formats	: &[],
+			});
+
+			let default_view = texture.create_view(&default());
+
+			CachedTexture { texture, default_view }
+		};
+
+		history.views.insert(view_entity, ViewHistory {
+			size,
+			textures	: [create_texture("crt_history_a_texture"), create_texture("crt_history_b_texture")],
+			write		: 0,
+		});
+	}
+}
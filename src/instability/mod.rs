@@ -0,0 +1,127 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_resource::{ShaderDefVal, ShaderRef, ShaderType},
+	},
+};
+
+use std::ops;
+
+use super::*;
+
+// $ uuidgen
+pub const INSTABILITY_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0xc6430252875040c5b3197806aacb91bdu128);
+pub const INSTABILITY_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0x240cd80751c245b5b2c8d9132c8329a0u128);
+
+pub struct InstabilityCrtPlugin;
+
+impl Plugin for InstabilityCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, INSTABILITY_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, INSTABILITY_PASS0_SHADER_HANDLE, "../../assets/shaders/instability/pass0.wgsl", Shader::from_wgsl);
+
+		app.add_plugins(CrtEffectPlugin::<CrtInstabilitySettings>::default());
+	}
+}
+
+// IMPORTANT! keep this in sync with src/instability/settings.wgsl
+#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct CrtInstabilitySettings {
+	pub roll_speed			: f32,		// Vertical hold loss, screen heights per second the picture rolls by
+	pub blanking_height		: f32,		// Height of the black blanking bar rolling with the picture in screen heights
+	pub jitter				: f32,		// Random horizontal displacement of every line in line heights
+	pub line_count			: f32,		// Lines of the picture, also the unit of jitter and wobble
+	pub wobble_amplitude	: f32,		// Sinusoidal horizontal displacement in line heights
+	pub wobble_frequency	: f32,		// Wobble waves per screen height
+	pub wobble_speed		: f32,		// Wobble waves passing per second
+}
+
+impl CrtInstabilitySettings {
+	pub const SUBTLE : Self = Self {
+		roll_speed			: 0.0,
+		blanking_height		: 0.08,
+		jitter				: 0.4,
+		line_count			: 480.0,
+		wobble_amplitude	: 0.7,
+		wobble_frequency	: 3.0,
+		wobble_speed		: 0.5,
+	};
+
+	pub const BREAKDOWN : Self = Self {
+		roll_speed			: 0.6,
+		blanking_height		: 0.08,
+		jitter				: 3.0,
+		line_count			: 480.0,
+		wobble_amplitude	: 5.0,
+		wobble_frequency	: 8.0,
+		wobble_speed		: 3.0,
+	};
+
+	pub fn new(preset: CrtInstabilityPreset) -> Self {
+		match preset {
+			CrtInstabilityPreset::Subtle	=> CrtInstabilitySettings::SUBTLE,
+			CrtInstabilityPreset::Breakdown	=> CrtInstabilitySettings::BREAKDOWN,
+		}
+	}
+
+	pub fn with_scale(mut self, scale: f32) -> Self {
+		self = self * scale;
+		self
+	}
+
+	pub fn set_preset_scaled(&mut self, preset: CrtInstabilityPreset, scale: f32) {
+		*self = CrtInstabilitySettings::new(preset).with_scale(scale);
+	}
+}
+
+impl ops::Mul<f32> for CrtInstabilitySettings {
+	type Output = CrtInstabilitySettings;
+
+	fn mul(self, rhs: f32) -> Self::Output {
+		let scale = rhs.max(MIN_SCALE);
+
+		Self::Output {
+			roll_speed			: self.roll_speed		* scale,
+			jitter				: self.jitter			* scale,
+			wobble_amplitude	: self.wobble_amplitude	* scale,
+			wobble_speed		: self.wobble_speed		* scale,
+			..self
+		}
+    }
+}
+
+impl Default for CrtInstabilitySettings {
+	fn default() -> Self {
+        CrtInstabilitySettings::SUBTLE
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrtInstabilityPreset {
+	Subtle,
+	Breakdown,
+}
+
+impl CrtEffect for CrtInstabilitySettings {
+	const NAME : &'static str = "instability";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![INSTABILITY_PASS0_SHADER_HANDLE.into()]
+	}
+
+	fn pass_enabled(&self, pass: usize) -> bool {
+		!self.shader_defs(pass).is_empty()
+	}
+
+	fn shader_defs(&self, _pass: usize) -> Vec<ShaderDefVal> {
+		let effects = [
+			("ROLL",	self.roll_speed),
+			("JITTER",	self.jitter),
+			("WOBBLE",	self.wobble_amplitude),
+		];
+
+		active_shader_defs(&effects)
+	}
+}
@@ -0,0 +1,11 @@
+#define_import_path bevy_crt_galore::instability
+
+struct CrtSettings {
+	roll_speed			: f32,		// Vertical hold loss, screen heights per second the picture rolls by
+	blanking_height		: f32,		// Height of the black blanking bar rolling with the picture in screen heights
+	jitter				: f32,		// Random horizontal displacement of every line in line heights
+	line_count			: f32,		// Lines of the picture, also the unit of jitter and wobble
+	wobble_amplitude	: f32,		// Sinusoidal horizontal displacement in line heights
+	wobble_frequency	: f32,		// Wobble waves per screen height
+	wobble_speed		: f32,		// Wobble waves passing per second
+}
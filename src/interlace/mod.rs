@@ -0,0 +1,128 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_resource::{ShaderDefVal, ShaderRef, ShaderType},
+	},
+};
+
+use std::ops;
+
+use super::*;
+
+// $ uuidgen
+pub const INTERLACE_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0x9e6bff06cb554de89fd5e334ed90657au128);
+pub const INTERLACE_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0x328faa17ca2545958069e5ff2c6066efu128);
+
+pub struct InterlaceCrtPlugin;
+
+impl Plugin for InterlaceCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, INTERLACE_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, INTERLACE_PASS0_SHADER_HANDLE, "../../assets/shaders/interlace/pass0.wgsl", Shader::from_wgsl);
+
+		app.add_plugins(CrtEffectPlugin::<CrtInterlaceSettings>::default());
+	}
+}
+
+/// Odd and even lines are refreshed on alternate frames. Put it first in a [`CrtChain`] so that the other families
+/// (masks in particular) are applied on top of the interlaced signal:
+/// ```ignore
+/// commands.spawn((
+///     Camera3dBundle::default(),
+///     CrtInterlaceSettings::NTSC_480I,
+///     CrtXorSettings::MILD,
+///     CrtChain::new().then::<CrtInterlaceSettings>().then::<CrtXorSettings>(),
+/// ));
+/// ```
+// IMPORTANT! keep this in sync with src/interlace/settings.wgsl
+#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct CrtInterlaceSettings {
+	pub line_count	: f32,		// Lines of both fields together, 480 or 576
+	pub combing		: f32,		// 1 keeps the other field from the previous frame (combing on motion), 0 doubles the current field
+	pub flicker		: f32,		// How much dimmer the field that isn't refreshed this frame is (0 to 1)
+}
+
+impl CrtInterlaceSettings {
+	pub const NTSC_480I : Self = Self {
+		line_count	: 480.0,
+		combing		: 1.0,
+		flicker		: 0.15,
+	};
+
+	pub const PAL_576I : Self = Self {
+		line_count	: 576.0,
+		combing		: 1.0,
+		flicker		: 0.15,
+	};
+
+	pub fn new(preset: CrtInterlacePreset) -> Self {
+		match preset {
+			CrtInterlacePreset::Ntsc480i	=> CrtInterlaceSettings::NTSC_480I,
+			CrtInterlacePreset::Pal576i		=> CrtInterlaceSettings::PAL_576I,
+		}
+	}
+
+	pub fn with_scale(mut self, scale: f32) -> Self {
+		self = self * scale;
+		self
+	}
+
+	pub fn set_preset_scaled(&mut self, preset: CrtInterlacePreset, scale: f32) {
+		*self = CrtInterlaceSettings::new(preset).with_scale(scale);
+	}
+}
+
+impl ops::Mul<f32> for CrtInterlaceSettings {
+	type Output = CrtInterlaceSettings;
+
+	fn mul(self, rhs: f32) -> Self::Output {
+		let scale = rhs.max(MIN_SCALE);
+
+		// line count is the video standard, it doesn't scale
+		Self::Output {
+			line_count	: self.line_count,
+			combing		: self.combing	* scale,
+			flicker		: self.flicker	* scale,
+		}
+    }
+}
+
+impl Default for CrtInterlaceSettings {
+	fn default() -> Self {
+        CrtInterlaceSettings::NTSC_480I
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrtInterlacePreset {
+	Ntsc480i,
+	Pal576i,
+}
+
+impl CrtEffect for CrtInterlaceSettings {
+	const NAME : &'static str = "interlace";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![INTERLACE_PASS0_SHADER_HANDLE.into()]
+	}
+
+	fn pass_enabled(&self, _pass: usize) -> bool {
+		self.line_count >= 2.0
+	}
+
+	fn shader_defs(&self, _pass: usize) -> Vec<ShaderDefVal> {
+		let effects = [
+			("COMBING",	self.combing),
+			("FLICKER",	self.flicker),
+		];
+
+		active_shader_defs(&effects)
+	}
+
+	// the field that isn't refreshed is kept from the previous frame
+	fn history_pass() -> Option<usize> {
+		Some(0)
+	}
+}
@@ -0,0 +1,7 @@
+#define_import_path bevy_crt_galore::interlace
+
+struct CrtSettings {
+	line_count			: f32,		// Lines of both fields together, 480 or 576
+	combing				: f32,		// 1 keeps the other field from the previous frame (combing on motion), 0 doubles the current field
+	flicker				: f32,		// How much dimmer the field that isn't refreshed this frame is (0 to 1)
+}
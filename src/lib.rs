@@ -1,26 +1,127 @@
-use bevy::prelude::*;
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponentPlugin,
+		RenderApp,
+	},
+};
 
 mod endesga;
 mod xor;
 mod gavlig;
+mod scanline;
+mod persistence;
+mod interlace;
+mod ntsc;
+mod pal;
+mod vhs;
+mod instability;
+mod bezel;
+mod halation;
+mod degauss;
+mod power;
+mod convergence;
+mod glass;
+mod placement;
+mod chain;
+mod bind_groups;
+mod effect;
+mod custom_pass;
+mod mask;
+mod history;
 
 use endesga :: *;
 use xor :: *;
 use gavlig :: *;
+use scanline :: *;
+use persistence :: *;
+use interlace :: *;
+use ntsc :: *;
+use pal :: *;
+use vhs :: *;
+use instability :: *;
+use bezel :: *;
+use halation :: *;
+use degauss :: *;
+use power :: *;
+use convergence :: *;
+use glass :: *;
+use placement :: *;
+use chain :: *;
+use bind_groups :: *;
+use effect :: *;
+use custom_pass :: *;
+use history :: *;
 
 pub use endesga	:: { CrtEndesgaSettings, CrtEndesgaPreset };
 pub use xor		:: { CrtXorSettings, CrtXorPreset };
 pub use gavlig	:: { CrtGavligSettings, CrtGavligPreset };
+pub use scanline	:: { CrtScanlineSettings, CrtScanlinePreset };
+pub use persistence	:: { CrtPersistenceSettings, CrtPersistencePreset };
+pub use interlace	:: { CrtInterlaceSettings, CrtInterlacePreset };
+pub use ntsc		:: { CrtNtscSettings, CrtNtscPreset };
+pub use pal		:: { CrtPalSettings, CrtPalPreset };
+pub use vhs		:: { CrtVhsSettings, CrtVhsPreset };
+pub use instability	:: { CrtInstabilitySettings, CrtInstabilityPreset };
+pub use bezel		:: { CrtBezel, CrtBezelSettings, CrtBezelLayout, CrtBezelLayoutLoader, CrtBezelLayoutLoaderError, CrtBezelFit };
+pub use glass		:: { CrtGlassSettings, CrtGlassReflection, CrtGlassPreset };
+pub use halation	:: { CrtHalationSettings, CrtHalationPreset };
+pub use degauss		:: { CrtDegaussSettings, CrtDegaussEvent, CrtDegaussPreset };
+pub use power		:: { CrtPower, CrtPowerState, CrtPowerEvent, CrtPowerSettings };
+pub use convergence	:: { CrtConvergenceSettings, CrtConvergencePreset };
+pub use placement	:: CrtPlacement;
+pub use chain		:: CrtChain;
+pub use effect		:: { CrtEffect, CrtEffectPlugin };
+pub use custom_pass	:: { CrtAppExt, CrtPassSlot };
+pub use mask		:: { CrtMaskType, CrtMaskTexture };
+
+// $ uuidgen
+pub const COMMON_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0x3c8f0d2e6b9a4f1c8e7d5a2b9c4e6f01u128);
+pub const MASK_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0xe764d1a0383e419a8d344a03305fa6bbu128);
 
 pub struct CrtGalorePlugin;
 
 impl Plugin for CrtGalorePlugin {
 	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, COMMON_SHADER_HANDLE, "common.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, MASK_SHADER_HANDLE, "mask.wgsl", Shader::from_wgsl);
+
+		app.add_plugins((
+			ExtractComponentPlugin::<CrtPlacement>::default(),
+			ExtractComponentPlugin::<CrtChain>::default(),
+			ExtractComponentPlugin::<CrtMaskTexture>::default(),
+		));
+
+		// registration order is the default order of families on a camera, see CrtChain
 		app.add_plugins((
 			EndesgaCrtPlugin,
 			XorCrtPlugin,
 			GavligCrtPlugin,
+			ScanlineCrtPlugin,
+			PersistenceCrtPlugin,
+			InterlaceCrtPlugin,
+			NtscCrtPlugin,
+			PalCrtPlugin,
+			VhsCrtPlugin,
+			InstabilityCrtPlugin,
+			// bevy plugin tuples are limited to 15 entries
+			(
+				ConvergenceCrtPlugin,
+				DegaussCrtPlugin,
+				HalationCrtPlugin,
+				// the glass and the bezel stay visible on a turned off tube
+				PowerCrtPlugin,
+				GlassCrtPlugin,
+				BezelCrtPlugin,
+			),
 		));
+
+		let Ok(render_app) = app.get_sub_app_mut(RenderApp) else { return; };
+
+		for placement in CrtPlacement::ALL {
+			add_crt_node(render_app, CrtGaloreLabel(placement), placement, CrtGaloreNode { placement });
+		}
 	}
 }
 
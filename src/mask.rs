@@ -0,0 +1,35 @@
+use bevy::{
+	prelude::*,
+	render::extract_component::ExtractComponent,
+};
+
+/// Sub-pixel layout of the tube, selected with `mask_type` on the family settings, e.g. `CrtMaskType::SlotMask as u32`.
+/// `mask_size` is the pitch of one RGB triad in pixels, `mask_border` darkens the gaps between phosphors.
+// IMPORTANT! keep this in sync with src/mask.wgsl
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[repr(u32)]
+pub enum CrtMaskType {
+	/// ENDESGA's 7x4 hex sub-pixel mask, the border doesn't apply
+	#[default]
+	Hex = 0,
+	/// Xor's staggered RGB cells
+	Staggered = 1,
+	/// Trinitron style continuous vertical stripes
+	ApertureGrille = 2,
+	/// Tall slots with every other triad shifted by half a slot
+	SlotMask = 3,
+	/// Round phosphor dots arranged in triangles
+	DotTriad = 4,
+	/// Tile from [`CrtMaskTexture`] repeated every `mask_size` pixels, used as is so 1 keeps the brightness
+	Texture = 5,
+}
+
+impl From<CrtMaskType> for u32 {
+	fn from(mask_type: CrtMaskType) -> Self {
+		mask_type as u32
+	}
+}
+
+/// Mask tile for [`CrtMaskType::Texture`], e.g. a macro photo of a real tube. Goes on the camera next to the family settings.
+#[derive(Component, Clone, ExtractComponent)]
+pub struct CrtMaskTexture(pub Handle<Image>);
@@ -0,0 +1,129 @@
+#define_import_path bevy_crt_galore::mask
+
+// IMPORTANT! keep this in sync with CrtMaskType in src/mask.rs, hex is 0 and the fallback in crt_mask
+const MASK_STAGGERED = 1u;
+const MASK_APERTURE_GRILLE = 2u;
+const MASK_SLOT = 3u;
+const MASK_DOT_TRIAD = 4u;
+const MASK_TEXTURE = 5u;
+
+fn modulo(a: f32, b: f32) -> f32 {
+	return a - b * floor(a / b);
+}
+
+fn subpixel_color(index: f32) -> vec3<f32> {
+	return vec3<f32>(f32(index == 0.0), f32(index == 1.0), f32(index == 2.0));
+}
+
+// ENDESGA 7x4 hex sub-pixel mask, 7 pixels per triad at size 7
+fn hex_mask(pixel: vec2<f32>, size: f32) -> vec3<f32> {
+	// at the default size of 7 this is the frag_coord of the original Endesga lookup
+	let frag_coord = pixel * 7.0 / max(size, 1.0);
+
+	// this should be const, waiting for resolution of this: https://github.com/gfx-rs/wgpu/issues/4337
+	let X = vec3<f32>(0.0);
+	let R = vec3<f32>(1.0, 0.0, 0.0);
+	let G = vec3<f32>(0.0, 1.0, 0.0);
+	let B = vec3<f32>(0.0, 0.0, 1.0);
+	var M = array<vec3<f32>, 28>( X, X, X, X, X, X, X, X, R, R, G, G, B, B, X, R, R, G, G, B, B, X, R, R, G, G, B, B );
+
+	let uv_x = floor(frag_coord.x / 7.0);
+	let hex_offset = modulo(uv_x, 2.0) * 2.0;
+
+	return M[
+		i32(modulo(frag_coord.y + hex_offset, 4.0)) * 7 +
+		i32(modulo(frag_coord.x, 7.0))
+	];
+}
+
+// Xor's staggered RGB cells, every other column of cells is half a cell lower
+fn staggered_mask(pixel: vec2<f32>, size: f32, border: f32) -> vec3<f32> {
+	let coord = pixel / size;
+	let subcoord = coord * vec2<f32>(3.0, 1.0);
+	let cell_offset = vec2<f32>(0.0, fract(floor(coord.x) * 0.5));
+
+	// multiplied to maintain brightness
+	let color = subpixel_color(modulo(floor(subcoord.x), 3.0)) * 3.0;
+
+	let cell_uv = fract(subcoord + cell_offset) * 2.0 - 1.0;
+	let edges = 1.0 - cell_uv * cell_uv * border;
+
+	return color * edges.x * edges.y;
+}
+
+// Trinitron style continuous vertical stripes
+fn aperture_grille_mask(pixel: vec2<f32>, size: f32, border: f32) -> vec3<f32> {
+	let subcoord = pixel.x / size * 3.0;
+
+	let color = subpixel_color(modulo(floor(subcoord), 3.0)) * 3.0;
+
+	let stripe_u = fract(subcoord) * 2.0 - 1.0;
+
+	return color * (1.0 - stripe_u * stripe_u * border);
+}
+
+// slots twice as tall as the triad is wide, every other triad is half a slot lower, thin gaps between slots
+fn slot_mask(pixel: vec2<f32>, size: f32, border: f32) -> vec3<f32> {
+	let coord = pixel / vec2<f32>(size, size * 2.0);
+	let subcoord = coord * vec2<f32>(3.0, 1.0);
+	let slot_offset = vec2<f32>(0.0, fract(floor(coord.x) * 0.5));
+
+	let color = subpixel_color(modulo(floor(subcoord.x), 3.0)) * 3.0;
+
+	let slot_uv = fract(subcoord + slot_offset) * 2.0 - 1.0;
+	let edges = vec2<f32>(1.0 - slot_uv.x * slot_uv.x * border, 1.0 - pow(abs(slot_uv.y), 8.0) * border);
+
+	return color * edges.x * edges.y;
+}
+
+// round phosphor dots in triangles, rows offset by half a dot
+fn dot_triad_mask(pixel: vec2<f32>, size: f32, border: f32) -> vec3<f32> {
+	let spacing = vec2<f32>(size / 1.5, size / 1.5 * 0.866);
+
+	let row = floor(pixel.y / spacing.y);
+	let row_offset = modulo(row, 2.0) * 0.5;
+	let column = floor(pixel.x / spacing.x + row_offset);
+
+	let color = subpixel_color(modulo(column + row * 2.0, 3.0)) * 3.0;
+
+	let dot_uv = (fract(vec2<f32>(pixel.x / spacing.x + row_offset, pixel.y / spacing.y)) * 2.0 - 1.0) * vec2<f32>(1.0, 0.866);
+	let phosphor = mix(1.0, 1.0 - smoothstep(0.6, 1.0, length(dot_uv)), border);
+
+	return color * phosphor;
+}
+
+// user tile repeated every size pixels horizontally, used as is
+fn texture_mask(pixel: vec2<f32>, size: f32, mask_texture: texture_2d<f32>, mask_sampler: sampler) -> vec3<f32> {
+	let tile_size = vec2<f32>(textureDimensions(mask_texture));
+	let tile = vec2<f32>(size, size * tile_size.y / tile_size.x);
+
+	// level 0 since a tiled mask needs no mips and crt_mask branches on mask_type, see textureSample uniformity
+	return textureSampleLevel(mask_texture, mask_sampler, pixel / tile, 0.0).rgb;
+}
+
+// sub-pixel mask of the given type at the pixel, size is the triad (or tile) pitch in pixels and border darkens the gaps (0 to 1)
+fn crt_mask(
+	pixel			: vec2<f32>,
+	mask_type		: u32,
+	size			: f32,
+	border			: f32,
+	mask_texture	: texture_2d<f32>,
+	mask_sampler	: sampler,
+) -> vec3<f32> {
+	let mask_size = max(size, 1.0);
+
+	if mask_type == MASK_STAGGERED {
+		return staggered_mask(pixel, mask_size, border);
+	} else if mask_type == MASK_APERTURE_GRILLE {
+		return aperture_grille_mask(pixel, mask_size, border);
+	} else if mask_type == MASK_SLOT {
+		return slot_mask(pixel, mask_size, border);
+	} else if mask_type == MASK_DOT_TRIAD {
+		return dot_triad_mask(pixel, mask_size, border);
+	} else if mask_type == MASK_TEXTURE {
+		return texture_mask(pixel, mask_size, mask_texture, mask_sampler);
+	}
+
+	// hex and anything unknown
+	return hex_mask(pixel, mask_size);
+}
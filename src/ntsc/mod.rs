@@ -0,0 +1,116 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_resource::{ShaderRef, ShaderType},
+	},
+};
+
+use std::ops;
+
+use super::*;
+
+// $ uuidgen
+pub const NTSC_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0xd52f25ac221c41b590ca4cab73c17e0fu128);
+pub const NTSC_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0xbb7522812518473fbaa6c6592a946435u128);
+
+pub struct NtscCrtPlugin;
+
+impl Plugin for NtscCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, NTSC_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, NTSC_PASS0_SHADER_HANDLE, "../../assets/shaders/ntsc/pass0.wgsl", Shader::from_wgsl);
+
+		app.add_plugins(CrtEffectPlugin::<CrtNtscSettings>::default());
+	}
+}
+
+/// Encodes every line into a YIQ composite signal and decodes it back, dithered patterns blend into solid colors
+/// and fine detail gets rainbows. Best put first in a [`CrtChain`], the signal is what the rest of the screen receives.
+// IMPORTANT! keep this in sync with src/ntsc/settings.wgsl
+#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct CrtNtscSettings {
+	pub resolution			: Vec2,		// Signal samples per line and lines per frame
+	pub subcarrier_period	: f32,		// Samples per color subcarrier cycle
+	pub luma_bandwidth		: f32,		// Relative to the subcarrier, lower is blurrier. Above 1 the subcarrier leaks into luma (dot crawl)
+	pub chroma_bandwidth	: f32,		// Relative to the subcarrier, lower bleeds color further
+	pub artifacts			: f32,		// 1 decodes everything from the composite signal, 0 keeps luma and chroma apart like S-Video
+	pub dot_crawl			: f32,		// Subcarrier phase shift per frame in half cycles, 1 is broadcast NTSC
+}
+
+impl CrtNtscSettings {
+	pub const COMPOSITE : Self = Self {
+		resolution			: Vec2::new(640.0, 240.0),
+		subcarrier_period	: 3.5,
+		luma_bandwidth		: 1.5,
+		chroma_bandwidth	: 0.5,
+		artifacts			: 1.0,
+		dot_crawl			: 1.0,
+	};
+
+	pub const SVIDEO : Self = Self {
+		resolution			: Vec2::new(640.0, 240.0),
+		subcarrier_period	: 3.5,
+		luma_bandwidth		: 2.0,
+		chroma_bandwidth	: 0.7,
+		artifacts			: 0.0,
+		dot_crawl			: 0.0,
+	};
+
+	pub fn new(preset: CrtNtscPreset) -> Self {
+		match preset {
+			CrtNtscPreset::Composite	=> CrtNtscSettings::COMPOSITE,
+			CrtNtscPreset::SVideo		=> CrtNtscSettings::SVIDEO,
+		}
+	}
+
+	pub fn with_scale(mut self, scale: f32) -> Self {
+		self = self * scale;
+		self
+	}
+
+	pub fn set_preset_scaled(&mut self, preset: CrtNtscPreset, scale: f32) {
+		*self = CrtNtscSettings::new(preset).with_scale(scale);
+	}
+}
+
+impl ops::Mul<f32> for CrtNtscSettings {
+	type Output = CrtNtscSettings;
+
+	fn mul(self, rhs: f32) -> Self::Output {
+		let scale = rhs.max(MIN_SCALE);
+
+		// less effect means wider bandwidth
+		Self::Output {
+			luma_bandwidth		: self.luma_bandwidth	/ scale,
+			chroma_bandwidth	: self.chroma_bandwidth	/ scale,
+			artifacts			: self.artifacts		* scale,
+			..self
+		}
+    }
+}
+
+impl Default for CrtNtscSettings {
+	fn default() -> Self {
+        CrtNtscSettings::COMPOSITE
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrtNtscPreset {
+	Composite,
+	SVideo,
+}
+
+impl CrtEffect for CrtNtscSettings {
+	const NAME : &'static str = "ntsc";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![NTSC_PASS0_SHADER_HANDLE.into()]
+	}
+
+	fn pass_enabled(&self, _pass: usize) -> bool {
+		self.resolution.min_element() >= 1.0 && self.subcarrier_period > MIN_AMOUNT
+	}
+}
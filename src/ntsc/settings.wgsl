@@ -0,0 +1,10 @@
+#define_import_path bevy_crt_galore::ntsc
+
+struct CrtSettings {
+	resolution			: vec2<f32>,	// Signal samples per line and lines per frame
+	subcarrier_period	: f32,			// Samples per color subcarrier cycle
+	luma_bandwidth		: f32,			// Relative to the subcarrier, lower is blurrier. Above 1 the subcarrier leaks into luma (dot crawl)
+	chroma_bandwidth	: f32,			// Relative to the subcarrier, lower bleeds color further
+	artifacts			: f32,			// 1 decodes everything from the composite signal, 0 keeps luma and chroma apart like S-Video
+	dot_crawl			: f32,			// Subcarrier phase shift per frame in half cycles, 1 is broadcast NTSC
+}
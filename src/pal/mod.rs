@@ -0,0 +1,128 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_resource::{ShaderDefVal, ShaderRef, ShaderType},
+	},
+};
+
+use std::ops;
+
+use super::*;
+
+// $ uuidgen
+pub const PAL_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0x048aa024d4624b8da1ead33f0ee47c15u128);
+pub const PAL_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0xbfc84b8a022149cca8860c6cc4cf5474u128);
+
+pub struct PalCrtPlugin;
+
+impl Plugin for PalCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, PAL_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, PAL_PASS0_SHADER_HANDLE, "../../assets/shaders/pal/pass0.wgsl", Shader::from_wgsl);
+
+		app.add_plugins(CrtEffectPlugin::<CrtPalSettings>::default());
+	}
+}
+
+/// Encodes every line into a YUV composite signal with the V phase alternating per line and decodes it back.
+/// Phase errors turn into desaturation with the delay line and into Hanover bars without it.
+// IMPORTANT! keep this in sync with src/pal/settings.wgsl
+#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct CrtPalSettings {
+	pub resolution			: Vec2,		// Signal samples per line and lines per frame
+	pub subcarrier_period	: f32,		// Samples per color subcarrier cycle
+	pub luma_bandwidth		: f32,		// Relative to the subcarrier, lower is blurrier
+	pub chroma_bandwidth	: f32,		// Relative to the subcarrier, lower bleeds color further
+	pub artifacts			: f32,		// 1 decodes everything from the composite signal, 0 keeps luma and chroma apart
+	pub phase_error			: f32,		// Decoder subcarrier phase error in radians
+	pub delay_line			: f32,		// 1 averages chroma with the previous line (PAL-D), 0 shows phase errors as Hanover bars (PAL-S)
+}
+
+impl CrtPalSettings {
+	pub const PAL_D : Self = Self {
+		resolution			: Vec2::new(720.0, 576.0),
+		subcarrier_period	: 3.13,
+		luma_bandwidth		: 1.2,
+		chroma_bandwidth	: 0.5,
+		artifacts			: 1.0,
+		phase_error			: 0.2,
+		delay_line			: 1.0,
+	};
+
+	pub const PAL_S : Self = Self {
+		resolution			: Vec2::new(720.0, 576.0),
+		subcarrier_period	: 3.13,
+		luma_bandwidth		: 1.2,
+		chroma_bandwidth	: 0.5,
+		artifacts			: 1.0,
+		phase_error			: 0.35,
+		delay_line			: 0.0,
+	};
+
+	pub fn new(preset: CrtPalPreset) -> Self {
+		match preset {
+			CrtPalPreset::PalD => CrtPalSettings::PAL_D,
+			CrtPalPreset::PalS => CrtPalSettings::PAL_S,
+		}
+	}
+
+	pub fn with_scale(mut self, scale: f32) -> Self {
+		self = self * scale;
+		self
+	}
+
+	pub fn set_preset_scaled(&mut self, preset: CrtPalPreset, scale: f32) {
+		*self = CrtPalSettings::new(preset).with_scale(scale);
+	}
+}
+
+impl ops::Mul<f32> for CrtPalSettings {
+	type Output = CrtPalSettings;
+
+	fn mul(self, rhs: f32) -> Self::Output {
+		let scale = rhs.max(MIN_SCALE);
+
+		// less effect means wider bandwidth
+		Self::Output {
+			luma_bandwidth		: self.luma_bandwidth	/ scale,
+			chroma_bandwidth	: self.chroma_bandwidth	/ scale,
+			artifacts			: self.artifacts		* scale,
+			phase_error			: self.phase_error		* scale,
+			..self
+		}
+    }
+}
+
+impl Default for CrtPalSettings {
+	fn default() -> Self {
+        CrtPalSettings::PAL_D
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrtPalPreset {
+	PalD,
+	PalS,
+}
+
+impl CrtEffect for CrtPalSettings {
+	const NAME : &'static str = "pal";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![PAL_PASS0_SHADER_HANDLE.into()]
+	}
+
+	fn pass_enabled(&self, _pass: usize) -> bool {
+		self.resolution.min_element() >= 1.0 && self.subcarrier_period > MIN_AMOUNT
+	}
+
+	fn shader_defs(&self, _pass: usize) -> Vec<ShaderDefVal> {
+		let effects = [
+			("DELAY_LINE", self.delay_line),
+		];
+
+		active_shader_defs(&effects)
+	}
+}
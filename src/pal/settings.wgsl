@@ -0,0 +1,11 @@
+#define_import_path bevy_crt_galore::pal
+
+struct CrtSettings {
+	resolution			: vec2<f32>,	// Signal samples per line and lines per frame
+	subcarrier_period	: f32,			// Samples per color subcarrier cycle
+	luma_bandwidth		: f32,			// Relative to the subcarrier, lower is blurrier
+	chroma_bandwidth	: f32,			// Relative to the subcarrier, lower bleeds color further
+	artifacts			: f32,			// 1 decodes everything from the composite signal, 0 keeps luma and chroma apart
+	phase_error			: f32,			// Decoder subcarrier phase error in radians
+	delay_line			: f32,			// 1 averages chroma with the previous line (PAL-D), 0 shows phase errors as Hanover bars (PAL-S)
+}
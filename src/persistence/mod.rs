@@ -0,0 +1,99 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_resource::{ShaderRef, ShaderType},
+	},
+};
+
+use std::ops;
+
+use super::*;
+
+// $ uuidgen
+pub const PERSISTENCE_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0xde7811c281db468d840511c46e4b2b8eu128);
+pub const PERSISTENCE_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0x6941584ac2a74037b9dc49a93a34a530u128);
+
+pub struct PersistenceCrtPlugin;
+
+impl Plugin for PersistenceCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, PERSISTENCE_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, PERSISTENCE_PASS0_SHADER_HANDLE, "../../assets/shaders/persistence/pass0.wgsl", Shader::from_wgsl);
+
+		app.add_plugins(CrtEffectPlugin::<CrtPersistenceSettings>::default());
+	}
+}
+
+// IMPORTANT! keep this in sync with src/persistence/settings.wgsl
+#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct CrtPersistenceSettings {
+	pub decay_time	: Vec3,		// Seconds for the afterglow of each channel to fade to ~37%, phosphors decay at different speeds
+	pub intensity	: f32,		// How much of the afterglow is visible (0 to 1)
+}
+
+impl CrtPersistenceSettings {
+	pub const STRONG : Self = Self {
+		decay_time	: Vec3::new(0.25, 0.18, 0.12),
+		intensity	: 1.0,
+	};
+
+	pub const MILD : Self = Self {
+		decay_time	: Vec3::new(0.06, 0.045, 0.03),
+		intensity	: 0.6,
+	};
+
+	pub fn new(preset: CrtPersistencePreset) -> Self {
+		match preset {
+			CrtPersistencePreset::Mild		=> CrtPersistenceSettings::MILD,
+			CrtPersistencePreset::Strong	=> CrtPersistenceSettings::STRONG,
+		}
+	}
+
+	pub fn with_scale(mut self, scale: f32) -> Self {
+		self = self * scale;
+		self
+	}
+
+	pub fn set_preset_scaled(&mut self, preset: CrtPersistencePreset, scale: f32) {
+		*self = CrtPersistenceSettings::new(preset).with_scale(scale);
+	}
+}
+
+impl ops::Mul<f32> for CrtPersistenceSettings {
+	type Output = CrtPersistenceSettings;
+
+	fn mul(self, rhs: f32) -> Self::Output {
+		let scale = rhs.max(MIN_SCALE);
+
+		Self::Output {
+			decay_time	: self.decay_time	* scale,
+			intensity	: self.intensity	* scale,
+		}
+    }
+}
+
+impl Default for CrtPersistenceSettings {
+	fn default() -> Self {
+        CrtPersistenceSettings::MILD
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrtPersistencePreset {
+	Mild,
+	Strong
+}
+
+impl CrtEffect for CrtPersistenceSettings {
+	const NAME : &'static str = "persistence";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![PERSISTENCE_PASS0_SHADER_HANDLE.into()]
+	}
+
+	fn history_pass() -> Option<usize> {
+		Some(0)
+	}
+}
@@ -0,0 +1,6 @@
+#define_import_path bevy_crt_galore::persistence
+
+struct CrtSettings {
+	decay_time			: vec3<f32>,	// Seconds for the afterglow of each channel to fade to ~37%
+	intensity			: f32,			// How much of the afterglow is visible (0 to 1)
+}
@@ -0,0 +1,151 @@
+use bevy::{
+	core_pipeline::{
+		core_2d::graph::{Core2d, Node2d},
+		core_3d::graph::{Core3d, Node3d},
+	},
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_graph::{InternedRenderLabel, Node, RenderGraph, RenderLabel, ViewNode, ViewNodeRunner},
+	},
+	ui::graph::NodeUi,
+};
+
+/// Where in the camera's render graph CRT effects are applied.
+/// Cameras without this component use [`CrtPlacement::BeforeBloom`].
+#[derive(Component, ExtractComponent, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum CrtPlacement {
+	/// Right after the main pass, in linear HDR color. Glow is picked up by Bevy's bloom.
+	#[default]
+	BeforeBloom,
+	/// After tonemapping, in display-referred color, before anti-aliasing.
+	AfterTonemapping,
+	/// After anti-aliasing, before the UI is drawn.
+	AfterAntiAliasing,
+	/// After the UI pass, so the HUD gets curved and masked together with the scene.
+	AfterUi,
+}
+
+impl CrtPlacement {
+	pub const ALL : [CrtPlacement; 4] = [
+		CrtPlacement::BeforeBloom,
+		CrtPlacement::AfterTonemapping,
+		CrtPlacement::AfterAntiAliasing,
+		CrtPlacement::AfterUi,
+	];
+}
+
+/// Nodes a placement is ordered against. The CRT node is only added when all `required` nodes exist,
+/// `optional` ones share the same span of the graph (e.g. CAS runs between Fxaa and the end of post processing)
+/// and only get an edge when their plugin is present.
+struct CrtPlacementEdges {
+	required	: [(InternedRenderLabel, InternedRenderLabel); 2],
+	optional	: Vec<(InternedRenderLabel, InternedRenderLabel)>,
+}
+
+impl CrtPlacementEdges {
+	fn core_3d(placement: CrtPlacement, label: InternedRenderLabel) -> Self {
+		let cas = Node3d::ContrastAdaptiveSharpening.intern();
+
+		match placement {
+			CrtPlacement::BeforeBloom => Self {
+				required	: [(Node3d::EndMainPass.intern(), label), (label, Node3d::Bloom.intern())],
+				optional	: Vec::new(),
+			},
+			CrtPlacement::AfterTonemapping => Self {
+				required	: [(Node3d::Tonemapping.intern(), label), (label, Node3d::Fxaa.intern())],
+				optional	: vec![(label, cas)],
+			},
+			CrtPlacement::AfterAntiAliasing => Self {
+				required	: [(Node3d::Fxaa.intern(), label), (label, Node3d::EndMainPassPostProcessing.intern())],
+				optional	: vec![(cas, label)],
+			},
+			CrtPlacement::AfterUi => Self {
+				required	: [(NodeUi::UiPass.intern(), label), (label, Node3d::Upscaling.intern())],
+				optional	: Vec::new(),
+			},
+		}
+	}
+
+	fn core_2d(placement: CrtPlacement, label: InternedRenderLabel) -> Self {
+		// sic, that's how bevy 0.13 spells it
+		let cas = Node2d::ConstrastAdaptiveSharpening.intern();
+
+		match placement {
+			CrtPlacement::BeforeBloom => Self {
+				required	: [(Node2d::MainPass.intern(), label), (label, Node2d::Bloom.intern())],
+				optional	: Vec::new(),
+			},
+			CrtPlacement::AfterTonemapping => Self {
+				required	: [(Node2d::Tonemapping.intern(), label), (label, Node2d::Fxaa.intern())],
+				optional	: vec![(label, cas)],
+			},
+			CrtPlacement::AfterAntiAliasing => Self {
+				required	: [(Node2d::Fxaa.intern(), label), (label, Node2d::EndMainPassPostProcessing.intern())],
+				optional	: vec![(cas, label)],
+			},
+			CrtPlacement::AfterUi => Self {
+				required	: [(NodeUi::UiPass.intern(), label), (label, Node2d::Upscaling.intern())],
+				optional	: Vec::new(),
+			},
+		}
+	}
+}
+
+fn add_crt_node_to_graph(
+	graph		: &mut RenderGraph,
+	graph_name	: &str,
+	label		: InternedRenderLabel,
+	placement	: CrtPlacement,
+	edges		: CrtPlacementEdges,
+	node		: impl Node,
+) {
+	let has_node = |graph: &RenderGraph, node: InternedRenderLabel| node == label || graph.get_node_state(node).is_ok();
+
+	let missing = edges.required.iter()
+		.flat_map(|&(from, to)| [from, to])
+		.find(|&node| !has_node(graph, node));
+
+	if let Some(missing) = missing {
+		warn!("bevy_crt_galore: {missing:?} not found in {graph_name} graph, CrtPlacement::{placement:?} is not available");
+		return;
+	}
+
+	graph.add_node(label, node);
+
+	for (from, to) in edges.required {
+		graph.add_node_edge(from, to);
+	}
+
+	for (from, to) in edges.optional {
+		if has_node(graph, from) && has_node(graph, to) {
+			graph.add_node_edge(from, to);
+		}
+	}
+}
+
+/// Adds `node` to both Core3d and Core2d graphs at the given placement.
+pub(crate) fn add_crt_node<N>(
+	render_app	: &mut App,
+	label		: impl RenderLabel + Clone,
+	placement	: CrtPlacement,
+	node		: N,
+)
+where
+	N: ViewNode + Clone + Send + Sync + 'static,
+{
+	let node_3d = ViewNodeRunner::new(node.clone(), &mut render_app.world);
+	let node_2d = ViewNodeRunner::new(node, &mut render_app.world);
+
+	let label = label.intern();
+
+	let mut render_graph = render_app.world.resource_mut::<RenderGraph>();
+
+	if let Some(graph) = render_graph.get_sub_graph_mut(Core3d) {
+		add_crt_node_to_graph(graph, "Core3d", label, placement, CrtPlacementEdges::core_3d(placement, label), node_3d);
+	}
+
+	if let Some(graph) = render_graph.get_sub_graph_mut(Core2d) {
+		add_crt_node_to_graph(graph, "Core2d", label, placement, CrtPlacementEdges::core_2d(placement, label), node_2d);
+	}
+}
@@ -0,0 +1,316 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_resource::{ShaderRef, ShaderType},
+	},
+};
+
+use super::*;
+
+// $ uuidgen
+pub const POWER_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0x2aed62a2af724ac7804ad477b61d88bbu128);
+pub const POWER_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0xdc0cb33aedea47148f084e6737e4bbc5u128);
+
+pub struct PowerCrtPlugin;
+
+impl Plugin for PowerCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, POWER_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, POWER_PASS0_SHADER_HANDLE, "../../assets/shaders/power/pass0.wgsl", Shader::from_wgsl);
+
+		app
+			.add_event::<CrtPowerEvent>()
+			.add_plugins(CrtEffectPlugin::<CrtPowerSettings>::default())
+			.add_systems(Update, update_crt_power);
+	}
+}
+
+// IMPORTANT! keep this in sync with assets/shaders/power/pass0.wgsl
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CrtPowerState {
+	#[default]
+	On,
+	/// Picture collapses into a bright line and then a fading dot
+	TurningOff,
+	Off,
+	/// Picture expands from a line and warms up
+	TurningOn,
+}
+
+impl CrtPowerState {
+	fn shader_index(&self) -> u32 {
+		match self {
+			CrtPowerState::On			=> 0,
+			CrtPowerState::TurningOff	=> 1,
+			CrtPowerState::Off			=> 2,
+			CrtPowerState::TurningOn	=> 3,
+		}
+	}
+}
+
+/// Power switch of the camera's tube. Call [`CrtPower::power_off`] or [`CrtPower::power_on`] and wait for
+/// [`CrtPowerEvent`], e.g. to change the scene behind a turned off screen.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct CrtPower {
+	pub state			: CrtPowerState,
+	pub progress		: f32,		// Progress of the current transition (0 to 1), 1 when settled
+	pub off_duration	: f32,		// Seconds to turn off
+	pub on_duration		: f32,		// Seconds to turn on
+}
+
+impl CrtPower {
+	pub fn off() -> Self {
+		Self { state: CrtPowerState::Off, ..default() }
+	}
+
+	pub fn power_off(&mut self) {
+		if matches!(self.state, CrtPowerState::On | CrtPowerState::TurningOn) {
+			self.state = CrtPowerState::TurningOff;
+			self.progress = 0.0;
+		}
+	}
+
+	pub fn power_on(&mut self) {
+		if matches!(self.state, CrtPowerState::Off | CrtPowerState::TurningOff) {
+			self.state = CrtPowerState::TurningOn;
+			self.progress = 0.0;
+		}
+	}
+
+	pub fn is_transitioning(&self) -> bool {
+		matches!(self.state, CrtPowerState::TurningOff | CrtPowerState::TurningOn)
+	}
+}
+
+impl Default for CrtPower {
+	fn default() -> Self {
+		Self {
+			state			: CrtPowerState::On,
+			progress		: 1.0,
+			off_duration	: 0.6,
+			on_duration		: 1.2,
+		}
+	}
+}
+
+/// Sent when a power transition of the camera completes, `state` is either `On` or `Off`
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CrtPowerEvent {
+	pub camera	: Entity,
+	pub state	: CrtPowerState,
+}
+
+/// Kept in sync with [`CrtPower`], not meant to be inserted by hand.
+// IMPORTANT! keep this in sync with src/power/settings.wgsl
+#[derive(Component, Clone, Copy, PartialEq, ExtractComponent, ShaderType)]
+pub struct CrtPowerSettings {
+	pub state		: u32,		// 0 on, 1 turning off, 2 off, 3 turning on
+	pub progress	: f32,		// Progress of the transition (0 to 1)
+}
+
+fn update_crt_power(
+	mut commands	: Commands,
+	mut events		: EventWriter<CrtPowerEvent>,
+	mut removed		: RemovedComponents<CrtPower>,
+	mut cameras		: Query<(Entity, &mut CrtPower, Option<&mut CrtPowerSettings>)>,
+	time			: Res<Time>,
+) {
+	for entity in removed.read() {
+		if let Some(mut entity_commands) = commands.get_entity(entity) {
+			entity_commands.remove::<CrtPowerSettings>();
+		}
+	}
+
+	for (camera, mut power, settings) in &mut cameras {
+		let duration = match power.state {
+			CrtPowerState::TurningOff	=> Some(power.off_duration),
+			CrtPowerState::TurningOn	=> Some(power.on_duration),
+			_ => None,
+		};
+
+		if let Some(duration) = duration {
+			power.progress = (power.progress + time.delta_seconds() / duration.max(f32::EPSILON)).min(1.0);
+
+			if power.progress >= 1.0 {
+				power.state = if power.state == CrtPowerState::TurningOff { CrtPowerState::Off } else { CrtPowerState::On };
+
+				events.send(CrtPowerEvent { camera, state: power.state });
+			}
+		}
+
+		let new_settings = CrtPowerSettings {
+			state		: power.state.shader_index(),
+			progress	: power.progress,
+		};
+
+		match settings {
+			Some(mut settings) => { settings.set_if_neq(new_settings); },
+			None => { commands.entity(camera).insert(new_settings); },
+		}
+	}
+}
+
+impl CrtEffect for CrtPowerSettings {
+	const NAME : &'static str = "power";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![POWER_PASS0_SHADER_HANDLE.into()]
+	}
+
+	// nothing to do with the power on
+	fn pass_enabled(&self, _pass: usize) -> bool {
+		self.state != CrtPowerState::On.shader_index()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use bevy::ecs::event::ManualEventReader;
+	use std::time::Duration;
+
+	use super::*;
+
+	struct PowerTest {
+		app		: App,
+		camera	: Entity,
+		reader	: ManualEventReader<CrtPowerEvent>,
+	}
+
+	impl PowerTest {
+		fn new() -> Self {
+			let mut app = App::new();
+
+			app
+				.init_resource::<Time>()
+				.add_event::<CrtPowerEvent>()
+				.add_systems(Update, update_crt_power);
+
+			let camera = app.world.spawn(CrtPower::default()).id();
+			let reader = app.world.resource::<Events<CrtPowerEvent>>().get_reader();
+
+			Self { app, camera, reader }
+		}
+
+		fn power(&mut self) -> Mut<'_, CrtPower> {
+			self.app.world.get_mut::<CrtPower>(self.camera).unwrap()
+		}
+
+		/// Advances time by `millis`, returns the state and the events sent during the frame
+		fn step(&mut self, millis: u64) -> (CrtPowerState, Vec<CrtPowerState>) {
+			self.app.world.resource_mut::<Time>().advance_by(Duration::from_millis(millis));
+			self.app.update();
+
+			let events = self.app.world.resource::<Events<CrtPowerEvent>>();
+			let sent = self.reader.read(events).inspect(|event| assert_eq!(event.camera, self.camera)).map(|event| event.state).collect();
+
+			(self.app.world.get::<CrtPower>(self.camera).unwrap().state, sent)
+		}
+
+		fn settings(&self) -> CrtPowerSettings {
+			*self.app.world.get::<CrtPowerSettings>(self.camera).unwrap()
+		}
+	}
+
+	#[test]
+	fn settled_on_sends_nothing() {
+		let mut test = PowerTest::new();
+
+		assert_eq!(test.step(250), (CrtPowerState::On, vec![]));
+		assert_eq!(test.settings().state, 0);
+		assert!(!test.settings().pass_enabled(0));
+	}
+
+	#[test]
+	fn power_off_then_on() {
+		let mut test = PowerTest::new();
+
+		test.power().power_off();
+
+		// off takes 0.6 seconds
+		assert_eq!(test.step(250), (CrtPowerState::TurningOff, vec![]));
+		assert_eq!(test.settings().state, 1);
+		assert_eq!(test.step(250), (CrtPowerState::TurningOff, vec![]));
+		assert_eq!(test.step(250), (CrtPowerState::Off, vec![CrtPowerState::Off]));
+		assert_eq!(test.power().progress, 1.0);
+		assert_eq!(test.settings().state, 2);
+
+		// the event is sent once
+		assert_eq!(test.step(250), (CrtPowerState::Off, vec![]));
+		assert_eq!(test.step(250), (CrtPowerState::Off, vec![]));
+
+		test.power().power_on();
+
+		// on takes 1.2 seconds
+		for _ in 0..4 {
+			assert_eq!(test.step(250), (CrtPowerState::TurningOn, vec![]));
+			assert_eq!(test.settings().state, 3);
+			assert!(test.settings().progress < 1.0);
+		}
+
+		assert_eq!(test.step(250), (CrtPowerState::On, vec![CrtPowerState::On]));
+		assert_eq!(test.power().progress, 1.0);
+		assert_eq!(test.settings().state, 0);
+
+		assert_eq!(test.step(250), (CrtPowerState::On, vec![]));
+	}
+
+	#[test]
+	fn progress_is_clamped() {
+		let mut test = PowerTest::new();
+
+		test.power().power_off();
+
+		assert_eq!(test.step(5000), (CrtPowerState::Off, vec![CrtPowerState::Off]));
+		assert_eq!(test.power().progress, 1.0);
+		assert_eq!(test.settings().progress, 1.0);
+	}
+
+	#[test]
+	fn redundant_switches_are_ignored() {
+		let mut test = PowerTest::new();
+
+		test.power().power_on();
+		assert_eq!(test.step(250), (CrtPowerState::On, vec![]));
+
+		test.power().power_off();
+		test.step(250);
+		test.power().power_off();
+
+		// not restarted by the second power_off
+		assert_eq!(test.step(250), (CrtPowerState::TurningOff, vec![]));
+		assert_eq!(test.step(250), (CrtPowerState::Off, vec![CrtPowerState::Off]));
+	}
+
+	#[test]
+	fn power_on_interrupts_power_off() {
+		let mut test = PowerTest::new();
+
+		test.power().power_off();
+		assert_eq!(test.step(250), (CrtPowerState::TurningOff, vec![]));
+
+		test.power().power_on();
+		assert_eq!(test.power().progress, 0.0);
+
+		// no Off event for the interrupted transition
+		for _ in 0..4 {
+			assert_eq!(test.step(250), (CrtPowerState::TurningOn, vec![]));
+		}
+
+		assert_eq!(test.step(250), (CrtPowerState::On, vec![CrtPowerState::On]));
+	}
+
+	#[test]
+	fn removing_power_removes_settings() {
+		let mut test = PowerTest::new();
+
+		test.step(250);
+		assert!(test.app.world.get::<CrtPowerSettings>(test.camera).is_some());
+
+		test.app.world.entity_mut(test.camera).remove::<CrtPower>();
+		test.app.update();
+
+		assert!(test.app.world.get::<CrtPowerSettings>(test.camera).is_none());
+	}
+}
@@ -0,0 +1,6 @@
+#define_import_path bevy_crt_galore::power
+
+struct CrtSettings {
+	state				: u32,		// 0 on, 1 turning off, 2 off, 3 turning on
+	progress			: f32,		// Progress of the transition (0 to 1)
+}
@@ -0,0 +1,110 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_resource::{ShaderRef, ShaderType},
+	},
+};
+
+use std::ops;
+
+use super::*;
+
+// $ uuidgen
+pub const SCANLINE_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0x08f162b7beeb425ea53abc7e1427dfdfu128);
+pub const SCANLINE_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0x07c97d2920a94ee9bdb40910adf81063u128);
+
+pub struct ScanlineCrtPlugin;
+
+impl Plugin for ScanlineCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, SCANLINE_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, SCANLINE_PASS0_SHADER_HANDLE, "../../assets/shaders/scanline/pass0.wgsl", Shader::from_wgsl);
+
+		app.add_plugins(CrtEffectPlugin::<CrtScanlineSettings>::default());
+	}
+}
+
+// IMPORTANT! keep this in sync with src/scanline/settings.wgsl
+#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct CrtScanlineSettings {
+	pub intensity		: f32,		// How dark the gaps between lines get (0 to 1)
+	pub line_count		: f32,		// Virtual vertical resolution, one beam per line
+	pub beam_width_min	: f32,		// Beam width of black pixels in lines
+	pub beam_width_max	: f32,		// Beam width of white pixels in lines, bright beams bloom into the gaps
+	pub brightness		: f32,		// Compensates for the light lost in the gaps
+}
+
+impl CrtScanlineSettings {
+	pub const STRONG : Self = Self {
+		intensity		: 1.0,
+		line_count		: 240.0,
+		beam_width_min	: 0.35,
+		beam_width_max	: 0.9,
+		brightness		: 1.5,
+	};
+
+	pub const MILD : Self = Self {
+		intensity		: 0.5,
+		line_count		: 480.0,
+		beam_width_min	: 0.6,
+		beam_width_max	: 1.0,
+		brightness		: 1.2,
+	};
+
+	pub fn new(preset: CrtScanlinePreset) -> Self {
+		match preset {
+			CrtScanlinePreset::Mild		=> CrtScanlineSettings::MILD,
+			CrtScanlinePreset::Strong	=> CrtScanlineSettings::STRONG,
+		}
+	}
+
+	pub fn with_scale(mut self, scale: f32) -> Self {
+		self = self * scale;
+		self
+	}
+
+	pub fn set_preset_scaled(&mut self, preset: CrtScanlinePreset, scale: f32) {
+		*self = CrtScanlineSettings::new(preset).with_scale(scale);
+	}
+}
+
+impl ops::Mul<f32> for CrtScanlineSettings {
+	type Output = CrtScanlineSettings;
+
+	fn mul(self, rhs: f32) -> Self::Output {
+		let scale = rhs.max(MIN_SCALE);
+
+		// line count and beam profile describe the virtual screen, only the strength scales
+		Self::Output {
+			intensity		: self.intensity * scale,
+			brightness		: 1.0 + (self.brightness - 1.0) * scale,
+			..self
+		}
+    }
+}
+
+impl Default for CrtScanlineSettings {
+	fn default() -> Self {
+        CrtScanlineSettings::MILD
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrtScanlinePreset {
+	Mild,
+	Strong
+}
+
+impl CrtEffect for CrtScanlineSettings {
+	const NAME : &'static str = "scanline";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![SCANLINE_PASS0_SHADER_HANDLE.into()]
+	}
+
+	fn pass_enabled(&self, _pass: usize) -> bool {
+		self.intensity > MIN_AMOUNT && self.line_count >= 1.0
+	}
+}
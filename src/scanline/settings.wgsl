@@ -0,0 +1,9 @@
+#define_import_path bevy_crt_galore::scanline
+
+struct CrtSettings {
+	intensity			: f32,		// How dark the gaps between lines get (0 to 1)
+	line_count			: f32,		// Virtual vertical resolution, one beam per line
+	beam_width_min		: f32,		// Beam width of black pixels in lines
+	beam_width_max		: f32,		// Beam width of white pixels in lines
+	brightness			: f32,		// Compensates for the light lost in the gaps
+}
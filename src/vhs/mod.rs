@@ -0,0 +1,156 @@
+use bevy::{
+	asset::load_internal_asset,
+	prelude::*,
+	render::{
+		extract_component::ExtractComponent,
+		render_resource::{ShaderDefVal, ShaderRef, ShaderType},
+	},
+};
+
+use std::ops;
+
+use super::*;
+
+// $ uuidgen
+pub const VHS_SETTINGS_SHADER_HANDLE	: Handle<Shader> = Handle::weak_from_u128(0xc12c12d75af64a1988b084e419a8a8eau128);
+pub const VHS_TAPE_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0xadc85d0b6c6742719124f2576a2cfeceu128);
+pub const VHS_PASS0_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0xffc89fbba98c4433a7cb91668e10d166u128);
+pub const VHS_PASS1_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0xf63bf631d3c34900bc6cea7bf48f8a7du128);
+pub const VHS_PASS2_SHADER_HANDLE		: Handle<Shader> = Handle::weak_from_u128(0x4b1e6c0f2d8a4e97a3c5f7019e2b6d48u128);
+
+pub struct VhsCrtPlugin;
+
+impl Plugin for VhsCrtPlugin {
+	fn build(&self, app: &mut App) {
+		load_internal_asset!(app, VHS_SETTINGS_SHADER_HANDLE, "settings.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, VHS_TAPE_SHADER_HANDLE, "tape.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, VHS_PASS0_SHADER_HANDLE, "../../assets/shaders/vhs/pass0.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, VHS_PASS1_SHADER_HANDLE, "../../assets/shaders/vhs/pass1.wgsl", Shader::from_wgsl);
+		load_internal_asset!(app, VHS_PASS2_SHADER_HANDLE, "../../assets/shaders/vhs/pass2.wgsl", Shader::from_wgsl);
+
+		app.add_plugins(CrtEffectPlugin::<CrtVhsSettings>::default());
+	}
+}
+
+/// VHS playback: tracking and head switching in pass0, smeared and delayed chroma in pass1, tape noise and dropouts in pass2.
+// IMPORTANT! keep this in sync with src/vhs/settings.wgsl
+#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+pub struct CrtVhsSettings {
+	pub resolution			: Vec2,		// Luma samples per line and lines per frame
+	pub chroma_resolution	: f32,		// Chroma samples per line, a fraction of luma on tape
+	pub chroma_delay		: f32,		// Chroma lagging behind luma in luma samples
+	pub tracking_error		: f32,		// Horizontal displacement inside the tracking band in screen widths
+	pub tracking_speed		: f32,		// Tracking band drift in screen heights per second
+	pub head_switching		: f32,		// Height of the distorted band at the bottom of the frame in screen heights
+	pub jitter				: f32,		// Random horizontal displacement of every line in luma samples
+	pub noise				: f32,		// Tape noise bands intensity (0 to 1)
+	pub dropouts			: f32,		// How often oxide dropouts streak through a line (0 to 1)
+}
+
+impl CrtVhsSettings {
+	pub const FRESH_TAPE : Self = Self {
+		resolution			: Vec2::new(333.0, 480.0),
+		chroma_resolution	: 60.0,
+		chroma_delay		: 1.5,
+		tracking_error		: 0.0,
+		tracking_speed		: 0.05,
+		head_switching		: 0.015,
+		jitter				: 0.3,
+		noise				: 0.1,
+		dropouts			: 0.05,
+	};
+
+	pub const WORN_RENTAL : Self = Self {
+		resolution			: Vec2::new(280.0, 480.0),
+		chroma_resolution	: 35.0,
+		chroma_delay		: 4.0,
+		tracking_error		: 0.015,
+		tracking_speed		: 0.08,
+		head_switching		: 0.03,
+		jitter				: 1.0,
+		noise				: 0.6,
+		dropouts			: 0.5,
+	};
+
+	pub fn new(preset: CrtVhsPreset) -> Self {
+		match preset {
+			CrtVhsPreset::FreshTape		=> CrtVhsSettings::FRESH_TAPE,
+			CrtVhsPreset::WornRental	=> CrtVhsSettings::WORN_RENTAL,
+		}
+	}
+
+	pub fn with_scale(mut self, scale: f32) -> Self {
+		self = self * scale;
+		self
+	}
+
+	pub fn set_preset_scaled(&mut self, preset: CrtVhsPreset, scale: f32) {
+		*self = CrtVhsSettings::new(preset).with_scale(scale);
+	}
+}
+
+impl ops::Mul<f32> for CrtVhsSettings {
+	type Output = CrtVhsSettings;
+
+	fn mul(self, rhs: f32) -> Self::Output {
+		let scale = rhs.max(MIN_SCALE);
+
+		// resolutions and tracking speed describe the tape, only the damage scales
+		Self::Output {
+			chroma_delay		: self.chroma_delay		* scale,
+			tracking_error		: self.tracking_error	* scale,
+			head_switching		: self.head_switching	* scale,
+			jitter				: self.jitter			* scale,
+			noise				: self.noise			* scale,
+			dropouts			: self.dropouts			* scale,
+			..self
+		}
+    }
+}
+
+impl Default for CrtVhsSettings {
+	fn default() -> Self {
+        CrtVhsSettings::FRESH_TAPE
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CrtVhsPreset {
+	FreshTape,
+	WornRental,
+}
+
+impl CrtEffect for CrtVhsSettings {
+	const NAME : &'static str = "vhs";
+
+	fn passes() -> Vec<ShaderRef> {
+		vec![
+			VHS_PASS0_SHADER_HANDLE.into(),
+			VHS_PASS1_SHADER_HANDLE.into(),
+			VHS_PASS2_SHADER_HANDLE.into(),
+		]
+	}
+
+	fn pass_enabled(&self, pass: usize) -> bool {
+		// chroma pass is what makes it a tape, the others only run with something to do
+		pass == 1 || !self.shader_defs(pass).is_empty()
+	}
+
+	fn shader_defs(&self, pass: usize) -> Vec<ShaderDefVal> {
+		let effects = match pass {
+			0 => vec![
+				("TRACKING",		self.tracking_error),
+				("HEAD_SWITCHING",	self.head_switching),
+				("JITTER",			self.jitter),
+			],
+			1 => vec![],
+			_ => vec![
+				("NOISE",			self.noise),
+				("DROPOUTS",		self.dropouts),
+				("TRACKING",		self.tracking_error),
+			],
+		};
+
+		active_shader_defs(&effects)
+	}
+}
@@ -0,0 +1,13 @@
+#define_import_path bevy_crt_galore::vhs
+
+struct CrtSettings {
+	resolution			: vec2<f32>,	// Luma samples per line and lines per frame
+	chroma_resolution	: f32,			// Chroma samples per line, a fraction of luma on tape
+	chroma_delay		: f32,			// Chroma lagging behind luma in luma samples
+	tracking_error		: f32,			// Horizontal displacement inside the tracking band in screen widths
+	tracking_speed		: f32,			// Tracking band drift in screen heights per second
+	head_switching		: f32,			// Height of the distorted band at the bottom of the frame in screen heights
+	jitter				: f32,			// Random horizontal displacement of every line in luma samples
+	noise				: f32,			// Tape noise bands intensity (0 to 1)
+	dropouts			: f32,			// How often oxide dropouts streak through a line (0 to 1)
+}
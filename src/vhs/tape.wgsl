@@ -0,0 +1,21 @@
+#define_import_path bevy_crt_galore::vhs_tape
+
+fn hash(p: vec2<f32>) -> f32 {
+	return fract(sin(dot(p, vec2<f32>(12.9898, 78.233))) * 43758.5453);
+}
+
+// smooth 1d value noise in -1..1
+fn value_noise(x: f32, seed: f32) -> f32 {
+	let i = floor(x);
+	let f = fract(x);
+	let a = hash(vec2<f32>(i, seed));
+	let b = hash(vec2<f32>(i + 1.0, seed));
+	return mix(a, b, f * f * (3.0 - 2.0 * f)) * 2.0 - 1.0;
+}
+
+// 1 in the middle of the tracking band drifting down the screen, 0 outside of it
+fn tracking_band(y: f32, time: f32, speed: f32) -> f32 {
+	let center = fract(time * speed);
+	let distance = min(abs(y - center), 1.0 - abs(y - center));
+	return smoothstep(0.08, 0.0, distance);
+}
@@ -4,6 +4,7 @@ struct CrtSettings {
 	mask_intensity		: f32,		// RGB Mask intensity(0 to 1)
 	mask_size			: f32,		// Mask size (in pixels)
 	mask_border			: f32,		// Border intensity (0 to 1)
+	mask_type			: u32,		// Sub-pixel layout, see CrtMaskType
 	aberration_offset	: vec2f,	// Chromatic abberration offset in texels (0 = no aberration)
 	screen_curvature	: f32,		// Curvature intensity
 	screen_vignette		: f32,		// Screen vignette